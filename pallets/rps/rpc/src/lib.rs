@@ -0,0 +1,214 @@
+//! RPC interface for the rock-paper-scissors pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use pallet_rps::{
+	ChallengeId, ChallengeState, CommitmentStatus, PlayerStatistics, SeasonId, SeasonPoints,
+	SeasonResult,
+};
+pub use pallet_rps_runtime_api::{Arena, RpsApi as RpsRuntimeApi};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i64 = 1;
+
+/// Open challenges along with the identifier to resume paging from, if any.
+pub type OpenChallengesPage<AccountId, Balance, BlockNumber, Hand, AssetId> = (
+	Vec<(ChallengeId, ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>)>,
+	Option<ChallengeId>,
+);
+
+/// RPC methods of the rock-paper-scissors pallet, each of them querying the given arena.
+#[rpc]
+pub trait RpsApi<BlockHash, AccountId, Balance, BlockNumber, Hand, AssetId> {
+	/// Returns the state of the given challenge.
+	#[rpc(name = "rps_challenge")]
+	fn challenge(
+		&self,
+		arena: Arena,
+		challenge_id: ChallengeId,
+		at: Option<BlockHash>,
+	) -> Result<Option<ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>>>;
+
+	/// Returns up to `count` open challenges, starting from the identifier `start`, along with
+	/// the identifier to resume from if some identifiers were not looked at.
+	#[rpc(name = "rps_openChallenges")]
+	fn open_challenges(
+		&self,
+		arena: Arena,
+		start: ChallengeId,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> Result<OpenChallengesPage<AccountId, Balance, BlockNumber, Hand, AssetId>>;
+
+	/// Returns every challenge in which the given account takes part.
+	#[rpc(name = "rps_challengesOf")]
+	fn challenges_of(
+		&self,
+		arena: Arena,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(ChallengeId, ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>)>>;
+
+	/// Returns the commitment status of a player in a challenge.
+	#[rpc(name = "rps_commitmentStatus")]
+	fn commitment_status(
+		&self,
+		arena: Arena,
+		challenge_id: ChallengeId,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Option<CommitmentStatus<Hand>>>;
+
+	/// Returns the statistics of the given account over the challenges it has finished.
+	#[rpc(name = "rps_playerStats")]
+	fn player_stats(
+		&self,
+		arena: Arena,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<PlayerStatistics<Balance, Hand>>;
+
+	/// Returns the players with the most points in the current season, best first.
+	#[rpc(name = "rps_leaderboard")]
+	fn leaderboard(
+		&self,
+		arena: Arena,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(AccountId, SeasonPoints)>>;
+
+	/// Returns the final standings and prizes of the given past season.
+	#[rpc(name = "rps_seasonResult")]
+	fn season_result(
+		&self,
+		arena: Arena,
+		season: SeasonId,
+		at: Option<BlockHash>,
+	) -> Result<Option<SeasonResult<AccountId, Balance, BlockNumber>>>;
+}
+
+/// Provides the RPC methods to query the rock-paper-scissors pallet.
+pub struct Rps<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Rps<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Runtime error".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber, Hand, AssetId>
+	RpsApi<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber, Hand, AssetId> for Rps<C, Block>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: RpsRuntimeApi<Block, AccountId, Balance, BlockNumber, Hand, AssetId>,
+	AccountId: Codec,
+	Balance: Codec,
+	BlockNumber: Codec,
+	Hand: Codec,
+	AssetId: Codec,
+{
+	fn challenge(
+		&self,
+		arena: Arena,
+		challenge_id: ChallengeId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.challenge(&at, arena, challenge_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn open_challenges(
+		&self,
+		arena: Arena,
+		start: ChallengeId,
+		count: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<OpenChallengesPage<AccountId, Balance, BlockNumber, Hand, AssetId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.open_challenges(&at, arena, start, count)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn challenges_of(
+		&self,
+		arena: Arena,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(ChallengeId, ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>)>>
+	{
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.challenges_of(&at, arena, account).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn commitment_status(
+		&self,
+		arena: Arena,
+		challenge_id: ChallengeId,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<CommitmentStatus<Hand>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.commitment_status(&at, arena, challenge_id, account)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn player_stats(
+		&self,
+		arena: Arena,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<PlayerStatistics<Balance, Hand>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.player_stats(&at, arena, account).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn leaderboard(
+		&self,
+		arena: Arena,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(AccountId, SeasonPoints)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.leaderboard(&at, arena).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn season_result(
+		&self,
+		arena: Arena,
+		season: SeasonId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<SeasonResult<AccountId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.season_result(&at, arena, season).map_err(runtime_error_into_rpc_err)
+	}
+}
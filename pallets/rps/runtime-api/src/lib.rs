@@ -0,0 +1,61 @@
+//! Runtime API definition for the rock-paper-scissors pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use pallet_rps::{
+	ChallengeId, ChallengeState, CommitmentStatus, PlayerStatistics, SeasonId, SeasonPoints,
+	SeasonResult,
+};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+/// Instance of the pallet a query is made against, for runtimes including a second arena.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Arena {
+	/// Default instance of the pallet.
+	Main,
+	/// Instance of the pallet configured as `Instance1`.
+	Instance1,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait RpsApi<AccountId, Balance, BlockNumber, Hand, AssetId> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		Hand: Codec,
+		AssetId: Codec,
+	{
+		/// Returns the state of the given challenge.
+		fn challenge(arena: Arena, challenge_id: ChallengeId) -> Option<ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>>;
+
+		/// Returns up to `count` open challenges, starting from the identifier `start`, along with
+		/// the identifier to resume from if some identifiers were not looked at.
+		fn open_challenges(
+			arena: Arena,
+			start: ChallengeId,
+			count: u32,
+		) -> (Vec<(ChallengeId, ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>)>, Option<ChallengeId>);
+
+		/// Returns every challenge in which the given account takes part.
+		fn challenges_of(
+			arena: Arena,
+			account: AccountId,
+		) -> Vec<(ChallengeId, ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>)>;
+
+		/// Returns the commitment status of a player in a challenge.
+		fn commitment_status(arena: Arena, challenge_id: ChallengeId, account: AccountId) -> Option<CommitmentStatus<Hand>>;
+
+		/// Returns the statistics of the given account over the challenges it has finished.
+		fn player_stats(arena: Arena, account: AccountId) -> PlayerStatistics<Balance, Hand>;
+
+		/// Returns the players with the most points in the current season, best first.
+		fn leaderboard(arena: Arena) -> Vec<(AccountId, SeasonPoints)>;
+
+		/// Returns the final standings and prizes of the given past season.
+		fn season_result(arena: Arena, season: SeasonId) -> Option<SeasonResult<AccountId, Balance, BlockNumber>>;
+	}
+}
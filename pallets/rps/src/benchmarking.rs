@@ -3,18 +3,466 @@
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use crate::Pallet as Rps;
+use frame_benchmarking::{account, benchmarks_instance_pallet, whitelisted_caller};
+use frame_support::{
+	sp_runtime::{
+		traits::{Bounded, One, Saturating, TrailingZeroInput, Zero},
+		Permill,
+	},
+	traits::{Currency, Hooks},
+};
 use frame_system::RawOrigin;
+use sp_std::vec;
 
-benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+const SEED: u32 = 0;
+
+fn funded_account<T: Config<I>, I: 'static>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T, I>::max_value() / 2_u32.into());
+	who
+}
+
+fn commitment<T: Config<I>, I: 'static>(
+	player: &T::AccountId,
+	challenge_id: ChallengeId,
+	hand: HandOf<T, I>,
+) -> ChallengePlayHash {
+	PlayCommitment::new(
+		[0; 32],
+		hand,
+		challenge_id,
+		0,
+		player.clone(),
+		frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+	)
+	.hash()
+}
+
+fn house_commitment<T: Config<I>, I: 'static>(
+	player: &T::AccountId,
+	hand: HandOf<T, I>,
+) -> ChallengePlayHash {
+	PlayCommitment::house(
+		[0; 32],
+		hand,
+		player.clone(),
+		HouseNonces::<T, I>::get(player),
+		frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+	)
+	.hash()
+}
+
+/// Creates a challenge between two funded accounts that is ready to be played.
+fn accepted_challenge<T: Config<I>, I: 'static>(
+) -> Result<(T::AccountId, T::AccountId), &'static str> {
+	let challenger = funded_account::<T, I>("challenger", 0);
+	let rival = funded_account::<T, I>("rival", 0);
+
+	Rps::<T, I>::create_challenge(
+		RawOrigin::Signed(challenger.clone()).into(),
+		None,
+		T::MinBetAmount::get(),
+		1,
+		None,
+	)?;
+	Rps::<T, I>::enter_challenge(RawOrigin::Signed(rival.clone()).into(), 0)?;
+
+	Ok((challenger, rival))
+}
+
+/// Creates a challenge in which the challenger played a hand beating the one of the rival.
+fn played_challenge<T: Config<I>, I: 'static>() -> Result<(T::AccountId, T::AccountId), &'static str>
+{
+	let (challenger, rival) = accepted_challenge::<T, I>()?;
+	let (winning, losing) = T::GameRules::winning_pair();
+
+	Rps::<T, I>::play_challenge(
+		RawOrigin::Signed(challenger.clone()).into(),
+		0,
+		commitment::<T, I>(&challenger, 0, winning),
+	)?;
+	Rps::<T, I>::play_challenge(
+		RawOrigin::Signed(rival.clone()).into(),
+		0,
+		commitment::<T, I>(&rival, 0, losing),
+	)?;
+
+	Ok((challenger, rival))
+}
+
+/// Lists `count` open challenges from distinct accounts in the lobby.
+fn fill_lobby<T: Config<I>, I: 'static>(count: u32) -> Result<(), &'static str> {
+	for i in 0..count {
+		let challenger = funded_account::<T, I>("lobby", i);
+		Rps::<T, I>::create_challenge(
+			RawOrigin::Signed(challenger).into(),
+			None,
+			T::MinBetAmount::get(),
+			1,
+			any_rating(),
+		)?;
+	}
+	Ok(())
+}
+
+/// Rating band admitting every rival, so that the rating checks are gone through.
+fn any_rating() -> Option<RatingBand> {
+	Some(RatingBand { min: 0, max: Rating::MAX })
+}
+
+/// Organizes a tournament with prizes for `MaxPrizeTiers` places and registers `players` funded
+/// accounts to it. Returns the block in which the bracket is seeded.
+fn open_tournament<T: Config<I>, I: 'static>(players: u32) -> Result<T::BlockNumber, &'static str> {
+	let organizer = funded_account::<T, I>("organizer", 0);
+	let deadline = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+	Rps::<T, I>::create_tournament(
+		RawOrigin::Signed(organizer).into(),
+		T::MinBetAmount::get(),
+		T::MaxTournamentPlayers::get(),
+		deadline,
+		1,
+		prize_shares::<T, I>(),
+	)?;
+	for i in 0..players {
+		let player = funded_account::<T, I>("player", i);
+		Rps::<T, I>::join_tournament(RawOrigin::Signed(player).into(), 0)?;
+	}
+	Ok(deadline.saturating_add(One::one()))
+}
+
+/// Shares of the prize pool spread over `MaxPrizeTiers` places, the first place getting the
+/// rounding leftover.
+fn prize_shares<T: Config<I>, I: 'static>() -> Vec<Permill> {
+	let places = T::MaxPrizeTiers::get().max(1);
+	let share = Permill::one().deconstruct() / places;
+	let mut shares = vec![Permill::from_parts(share); places as usize];
+	shares[0] = Permill::from_parts(Permill::one().deconstruct() - share * (places - 1));
+	shares
+}
+
+/// Funds the bankroll of the house and starts a game against it for `count` funded accounts, each
+/// committing to the winning hand of `winning_pair`. Returns the block in which the hands of the
+/// house are drawn.
+fn house_games<T: Config<I>, I: 'static>(
+	count: u32,
+) -> Result<(Vec<T::AccountId>, T::BlockNumber), &'static str> {
+	T::Currency::make_free_balance_be(
+		&Rps::<T, I>::house_account_id(),
+		BalanceOf::<T, I>::max_value() / 2_u32.into(),
+	);
+	let (winning, _) = T::GameRules::winning_pair();
+	let mut players = Vec::new();
+	for i in 0..count {
+		let player = funded_account::<T, I>("player", i);
+		Rps::<T, I>::play_house(
+			RawOrigin::Signed(player.clone()).into(),
+			house_commitment::<T, I>(&player, winning.clone()),
+			T::MinBetAmount::get(),
+		)?;
+		players.push(player);
+	}
+	Ok((players, frame_system::Pallet::<T>::block_number().saturating_add(One::one())))
+}
+
+fn winner_of<T: Config<I>, I: 'static>(challenge_id: ChallengeId) -> Option<T::AccountId> {
+	match ChallengeStore::<T, I>::get(challenge_id) {
+		Some(ChallengeState::Finished(finished)) => finished.winner,
+		_ => None,
+	}
+}
+
+benchmarks_instance_pallet! {
+	// Creating the last challenge that fits in the lobby
+	create_challenge {
+		fill_lobby::<T, I>(T::MaxLobbySize::get() - 1)?;
+		let challenge_id = NextBetId::<T, I>::get();
+		let caller = funded_account::<T, I>("caller", 0);
+	}: _(RawOrigin::Signed(caller), None, T::MinBetAmount::get(), 1, any_rating())
+	verify {
+		assert!(matches!(ChallengeStore::<T, I>::get(challenge_id), Some(ChallengeState::Open(_))));
+	}
+
+	create_challenge_for {
+		let caller = funded_account::<T, I>("caller", 0);
+		let rival: T::AccountId = account("rival", 0, SEED);
+	}: _(RawOrigin::Signed(caller), rival, None, T::MinBetAmount::get(), 1)
+	verify {
+		assert!(matches!(ChallengeStore::<T, I>::get(0), Some(ChallengeState::Open(_))));
+	}
+
+	cancel_challenge {
+		let caller = funded_account::<T, I>("caller", 0);
+		Rps::<T, I>::create_challenge(
+			RawOrigin::Signed(caller.clone()).into(),
+			None,
+			T::MinBetAmount::get(),
+			1,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(ChallengeStore::<T, I>::get(0).is_none());
+	}
+
+	enter_challenge {
+		let challenger = funded_account::<T, I>("challenger", 0);
+		let caller = funded_account::<T, I>("caller", 0);
+		Rps::<T, I>::create_challenge(
+			RawOrigin::Signed(challenger).into(),
+			None,
+			T::MinBetAmount::get(),
+			1,
+			any_rating(),
+		)?;
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(matches!(ChallengeStore::<T, I>::get(0), Some(ChallengeState::Accepted(_))));
+	}
+
+	// Joining from a full lobby
+	quick_join {
+		fill_lobby::<T, I>(T::MaxLobbySize::get())?;
+		let caller = funded_account::<T, I>("caller", 0);
+	}: _(RawOrigin::Signed(caller), None, T::MinBetAmount::get())
+	verify {
+		assert!(matches!(ChallengeStore::<T, I>::get(0), Some(ChallengeState::Accepted(_))));
+	}
+
+	// Joining a queue with a waiting player, which starts a challenge
+	join_queue {
+		let waiting = funded_account::<T, I>("waiting", 0);
+		Rps::<T, I>::join_queue(RawOrigin::Signed(waiting).into(), None, T::MinBetAmount::get())?;
+		let caller = funded_account::<T, I>("caller", 0);
+	}: _(RawOrigin::Signed(caller), None, T::MinBetAmount::get())
+	verify {
+		assert!(matches!(ChallengeStore::<T, I>::get(0), Some(ChallengeState::Accepted(_))));
+	}
+
+	leave_queue {
+		let caller = funded_account::<T, I>("caller", 0);
+		let bet = T::MinBetAmount::get();
+		Rps::<T, I>::join_queue(RawOrigin::Signed(caller.clone()).into(), None, bet)?;
+	}: _(RawOrigin::Signed(caller), None, T::MinBetAmount::get())
+	verify {
+		assert!(MatchmakingQueue::<T, I>::get((None::<T::AssetId>, bet)).is_none());
+	}
+
+	// The second play of a challenge, which also opens its reveal phase
+	play_challenge {
+		let (challenger, rival) = accepted_challenge::<T, I>()?;
+		let (winning, losing) = T::GameRules::winning_pair();
+		Rps::<T, I>::play_challenge(
+			RawOrigin::Signed(challenger).into(),
+			0,
+			commitment::<T, I>(&challenger, 0, winning),
+		)?;
+		let play_hash = commitment::<T, I>(&rival, 0, losing);
+	}: _(RawOrigin::Signed(rival.clone()), 0, play_hash)
+	verify {
+		assert!(ChallengePlaysStore::<T, I>::contains_key((0, 0, &rival)));
+	}
+
+	// The second reveal of a challenge, which settles it paying out the winner
+	reveal_play {
+		let (challenger, rival) = played_challenge::<T, I>()?;
+		let (winning, losing) = T::GameRules::winning_pair();
+		Rps::<T, I>::reveal_play(RawOrigin::Signed(challenger.clone()).into(), 0, winning, [0; 32])?;
+	}: _(RawOrigin::Signed(rival), 0, losing, [0; 32])
+	verify {
+		assert_eq!(winner_of::<T, I>(0), Some(challenger));
+	}
+
+	reveal_challenge_results {
+		let (challenger, _) = played_challenge::<T, I>()?;
+		let (winning, losing) = T::GameRules::winning_pair();
+	}: _(RawOrigin::Signed(challenger.clone()), winning, [0; 32], losing, [0; 32], 0)
+	verify {
+		assert_eq!(winner_of::<T, I>(0), Some(challenger));
+	}
+
+	// A reveal phase timeout, which pays out the only player that revealed
+	claim_timeout {
+		let (challenger, _) = played_challenge::<T, I>()?;
+		let (winning, _) = T::GameRules::winning_pair();
+		Rps::<T, I>::reveal_play(RawOrigin::Signed(challenger.clone()).into(), 0, winning, [0; 32])?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::RevealTimeout::get()));
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_eq!(winner_of::<T, I>(0), Some(challenger));
+	}
+
+	prune_challenge {
+		let (challenger, _) = played_challenge::<T, I>()?;
+		let (winning, losing) = T::GameRules::winning_pair();
+		Rps::<T, I>::reveal_challenge_results(
+			RawOrigin::Signed(challenger).into(),
+			winning,
+			[0; 32],
+			losing,
+			[0; 32],
+			0,
+		)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::FinishedRetention::get()));
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(ChallengeStore::<T, I>::get(0).is_none());
+	}
+
+	set_asset_min_bet {
+		let asset_id = T::AssetId::decode(&mut TrailingZeroInput::new(&[][..]))
+			.map_err(|_| "asset id can't be decoded")?;
+		let min_bet = T::MinBetAmount::get();
+	}: _(RawOrigin::Root, asset_id, Some(min_bet))
+	verify {
+		assert_eq!(AssetMinBet::<T, I>::get(asset_id), Some(min_bet));
+	}
+
+	create_tournament {
+		let caller = funded_account::<T, I>("caller", 0);
+		let deadline = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+		let prize_shares = vec![Permill::from_percent(50); 2];
+		let max_players = T::MaxTournamentPlayers::get();
+	}: _(RawOrigin::Signed(caller), T::MinBetAmount::get(), max_players, deadline, 1, prize_shares)
+	verify {
+		assert!(Tournaments::<T, I>::contains_key(0));
+	}
+
+	// Registering the last player of a full tournament
+	join_tournament {
+		open_tournament::<T, I>(T::MaxTournamentPlayers::get() - 1)?;
+		let caller = funded_account::<T, I>("caller", 0);
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert!(TournamentPlayers::<T, I>::get(0).contains(&caller));
+	}
+
+	// Seeding the first round of a tournament with `p` players
+	seed_tournament_round {
+		let p in 2 .. T::MaxTournamentPlayers::get();
+		let seeded_at = open_tournament::<T, I>(p)?;
+		frame_system::Pallet::<T>::set_block_number(seeded_at);
+	}: {
+		Rps::<T, I>::on_initialize(seeded_at);
+	}
+	verify {
+		assert_eq!(TournamentBracket::<T, I>::get(0).len() as u32, p.next_power_of_two() / 2);
+	}
+
+	// Settling the final match of a tournament with `p` players, which pays out its prizes. The
+	// earlier matches are forfeited by both players, so that their challengers advance.
+	finish_tournament {
+		let p in 2 .. T::MaxTournamentPlayers::get();
+		let mut now = open_tournament::<T, I>(p)?;
+		frame_system::Pallet::<T>::set_block_number(now);
+		Rps::<T, I>::on_initialize(now);
+		let caller: T::AccountId = whitelisted_caller();
+
+		let mut first_match = 0;
+		while TournamentBracket::<T, I>::get(0).len() > 1 {
+			let next_match = NextBetId::<T, I>::get();
+			now = now.saturating_add(T::PlayTimeout::get());
+			frame_system::Pallet::<T>::set_block_number(now);
+			for challenge_id in first_match..next_match {
+				Rps::<T, I>::claim_timeout(RawOrigin::Signed(caller.clone()).into(), challenge_id)?;
+			}
+			first_match = next_match;
+
+			now = now.saturating_add(One::one());
+			frame_system::Pallet::<T>::set_block_number(now);
+			Rps::<T, I>::on_initialize(now);
+		}
+		now = now.saturating_add(T::PlayTimeout::get());
+		frame_system::Pallet::<T>::set_block_number(now);
+	}: claim_timeout(RawOrigin::Signed(caller), first_match)
+	verify {
+		assert!(matches!(
+			Tournaments::<T, I>::get(0).map(|tournament| tournament.state),
+			Some(TournamentState::Finished { .. })
+		));
+	}
+
+	fund_season_pool {
+		let source = funded_account::<T, I>("source", 0);
+		let amount = T::MinBetAmount::get();
+	}: _(RawOrigin::Root, source, amount)
+	verify {
+		assert_eq!(SeasonPool::<T, I>::get(), amount);
+	}
+
+	// Ending a season whose pool is paid out to `w` places of the leaderboard
+	end_season {
+		let w in 0 .. T::LeaderboardSize::get().min(T::SeasonPrizeShares::get().len() as u32);
+		let source = funded_account::<T, I>("source", 0);
+		let pool = T::Currency::free_balance(&source) / 2_u32.into();
+		Rps::<T, I>::fund_season_pool(RawOrigin::Root.into(), source, pool)?;
+		let leaderboard: Vec<_> = (0..w).map(|i| (account("winner", i, SEED), w - i)).collect();
+		Leaderboard::<T, I>::put(
+			BoundedVec::try_from(leaderboard).map_err(|_| "leaderboard is too large")?,
+		);
+		let ends_at = T::SeasonLength::get();
+		frame_system::Pallet::<T>::set_block_number(ends_at);
+	}: {
+		Rps::<T, I>::on_initialize(ends_at);
+	}
+	verify {
+		assert_eq!(CurrentSeason::<T, I>::get(), 1);
+		assert!(SeasonResults::<T, I>::contains_key(0));
+	}
+
+	play_house {
+		house_games::<T, I>(0)?;
+		let caller = funded_account::<T, I>("caller", 0);
+		let (winning, _) = T::GameRules::winning_pair();
+		let play_hash = house_commitment::<T, I>(&caller, winning);
+	}: _(RawOrigin::Signed(caller.clone()), play_hash, T::MinBetAmount::get())
+	verify {
+		assert!(HouseGames::<T, I>::contains_key(&caller));
+	}
+
+	// Revealing a hand beating the one of the house, which pays the player
+	reveal_house {
+		let (players, drawn_at) = house_games::<T, I>(1)?;
+		let (winning, losing) = T::GameRules::winning_pair();
+		frame_system::Pallet::<T>::set_block_number(drawn_at);
+		HouseGames::<T, I>::mutate(&players[0], |game| {
+			if let Some(game) = game {
+				game.house_hand = Some((losing, drawn_at));
+			}
+		});
+	}: _(RawOrigin::Signed(players[0].clone()), winning, [0; 32])
+	verify {
+		assert!(!HouseGames::<T, I>::contains_key(&players[0]));
+	}
+
+	claim_house_timeout {
+		let (players, drawn_at) = house_games::<T, I>(1)?;
+		frame_system::Pallet::<T>::set_block_number(drawn_at);
+		Rps::<T, I>::on_initialize(drawn_at);
+		frame_system::Pallet::<T>::set_block_number(drawn_at.saturating_add(T::RevealTimeout::get()));
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+	}: _(RawOrigin::Signed(caller), players[0].clone())
+	verify {
+		assert!(!HouseGames::<T, I>::contains_key(&players[0]));
+	}
+
+	// Drawing the hands of the house for `h` games started in the previous block
+	draw_house_hands {
+		let h in 1 .. T::MaxHouseGamesPerBlock::get();
+		let (players, drawn_at) = house_games::<T, I>(h)?;
+		frame_system::Pallet::<T>::set_block_number(drawn_at);
+	}: {
+		Rps::<T, I>::on_initialize(drawn_at);
+	}
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		for player in players {
+			assert!(HouseGames::<T, I>::get(&player).and_then(|game| game.house_hand).is_some());
+		}
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(Rps, crate::mock::new_test_ext(), crate::mock::Test);
 }
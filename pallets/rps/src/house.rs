@@ -0,0 +1,27 @@
+//! Games played against the house, whose hand is drawn from on-chain randomness.
+
+use crate::ChallengePlayHash;
+use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+pub type HouseGameId = u64;
+
+/// Number of games an account has started against the house, which the commitment of its next
+/// game is bound to.
+pub type HouseNonce = u32;
+
+/// Single round game of a player against the house, settled once the player reveals its hand.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct HouseGame<Balance, BlockNumber, Hand> {
+	pub(crate) game_id: HouseGameId,
+	/// House nonce of the player the commitment is bound to, see `PlayCommitment::House`.
+	pub(crate) nonce: HouseNonce,
+	/// Bet of the player, matched by the house.
+	pub(crate) bet_amount: Balance,
+	pub(crate) commitment: ChallengePlayHash,
+	pub(crate) played_at: BlockNumber,
+	/// Hand of the house along with the block it was drawn in, the block after `played_at`.
+	pub(crate) house_hand: Option<(Hand, BlockNumber)>,
+}
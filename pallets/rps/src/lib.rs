@@ -1,6 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
 
 pub use pallet::*;
 
@@ -13,32 +16,113 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod house;
+pub use house::{HouseGame, HouseGameId, HouseNonce};
+
+pub mod migrations;
+
+pub mod rating;
+pub use rating::{Rating, RatingBand};
+
+pub mod rules;
+pub use rules::{ClassicRules, CyclicHand, CyclicRules, GameRules, RpslsPlay, RpslsRules};
+
+pub mod season;
+pub use season::{SeasonId, SeasonPoints, SeasonResult};
+
+pub mod tournament;
+pub use tournament::{Tournament, TournamentId, TournamentRound, TournamentState};
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 pub type ChallengeId = u64;
 
-pub type ChallengePlayHash = [u8; 8];
+pub type ChallengePlayHash = [u8; 32];
+
+pub type ChallengePlaySalt = [u8; 32];
+
+/// Index of a round within a challenge, starting at zero.
+pub type ChallengeRound = u32;
+
+/// Round counts a challenge can be played to, the winner being the first player to win a
+/// majority of them.
+pub const ALLOWED_ROUNDS: [u8; 4] = [1, 3, 5, 7];
+
+/// Maximum number of challenge identifiers looked at by a call to `open_challenges`, which bounds
+/// its cost however few of them are still open.
+pub const MAX_SCANNED_CHALLENGES: ChallengeId = 1_000;
 
 #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
-pub struct OpenChallenge<AccountId: PartialEq + Clone, Balance> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OpenChallenge<AccountId, Balance, BlockNumber, AssetId> {
 	challenger: AccountId,
+	/// Asset the bets are placed in, or `None` for the native currency.
+	asset: Option<AssetId>,
 	bet_amount: Balance,
+	/// Storage deposit reserved from the challenger until the challenge is pruned.
+	deposit: Balance,
+	/// Only account allowed to enter the challenge, if it was addressed to a specific rival.
+	intended_rival: Option<AccountId>,
+	/// Ratings rivals must have to enter the challenge, if it is restricted to some of them.
+	rating_band: Option<RatingBand>,
+	/// Number of rounds the match is played to, one of `ALLOWED_ROUNDS`.
+	rounds: u8,
+	created_at: BlockNumber,
 }
 
 #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
-pub struct AcceptedChallenge<AccountId: PartialEq + Clone, Balance> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AcceptedChallenge<AccountId, Balance, BlockNumber, AssetId> {
 	challenger: AccountId,
 	rival: AccountId,
+	/// Asset the bets are placed in, or `None` for the native currency.
+	asset: Option<AssetId>,
 	bet_amount: Balance,
+	deposit: Balance,
+	rounds: u8,
+	/// Round currently being played, drawn rounds included.
+	round: ChallengeRound,
+	/// Rounds won by the challenger and by the rival.
+	score: (u8, u8),
+	accepted_at: BlockNumber,
+	/// Block in which the current round started, opening its play phase.
+	round_started_at: BlockNumber,
+	/// Block in which both players had played the current round, opening its reveal phase.
+	reveal_started_at: Option<BlockNumber>,
 }
 
-impl<AccountId: PartialEq + Clone, Balance> AcceptedChallenge<AccountId, Balance> {
-	pub fn from_open(open_challenge: OpenChallenge<AccountId, Balance>, rival: AccountId) -> Self {
+impl<AccountId: PartialEq + Clone, Balance, BlockNumber, AssetId>
+	AcceptedChallenge<AccountId, Balance, BlockNumber, AssetId>
+{
+	pub fn from_open(
+		open_challenge: OpenChallenge<AccountId, Balance, BlockNumber, AssetId>,
+		rival: AccountId,
+		accepted_at: BlockNumber,
+	) -> Self
+	where
+		BlockNumber: Clone,
+	{
 		AcceptedChallenge {
 			challenger: open_challenge.challenger,
 			rival,
+			asset: open_challenge.asset,
 			bet_amount: open_challenge.bet_amount,
+			deposit: open_challenge.deposit,
+			rounds: open_challenge.rounds,
+			round: 0,
+			score: (0, 0),
+			accepted_at: accepted_at.clone(),
+			round_started_at: accepted_at,
+			reveal_started_at: None,
 		}
 	}
 
+	/// Number of round wins a player needs to win the match.
+	pub fn wins_needed(&self) -> u8 {
+		self.rounds / 2 + 1
+	}
+
 	pub fn contains_player(&self, player: &AccountId) -> bool {
 		self.challenger == *player || self.rival == *player
 	}
@@ -55,155 +139,887 @@ impl<AccountId: PartialEq + Clone, Balance> AcceptedChallenge<AccountId, Balance
 }
 
 #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
-pub struct FinishedChallenge<AccountId: PartialEq + Clone, Balance> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FinishedChallenge<AccountId, Balance, BlockNumber, Hand, AssetId> {
 	challenger: AccountId,
 	rival: AccountId,
+	/// Asset the bets are placed in, or `None` for the native currency.
+	asset: Option<AssetId>,
 	bet_amount: Balance,
+	deposit: Balance,
+	rounds: u8,
+	/// Rounds won by the challenger and by the rival.
+	score: (u8, u8),
+	/// Hands revealed by the challenger and the rival in the last round, if they revealed them.
+	hands: (Option<Hand>, Option<Hand>),
 	winner: Option<AccountId>,
+	finished_at: BlockNumber,
 }
 
-impl<AccountId: PartialEq + Clone, Balance> FinishedChallenge<AccountId, Balance> {
+impl<AccountId: PartialEq + Clone, Balance, BlockNumber, Hand: Clone, AssetId>
+	FinishedChallenge<AccountId, Balance, BlockNumber, Hand, AssetId>
+{
 	pub fn from_accepted(
-		accepted_challenge: AcceptedChallenge<AccountId, Balance>,
+		accepted_challenge: AcceptedChallenge<AccountId, Balance, BlockNumber, AssetId>,
+		hands: (Option<Hand>, Option<Hand>),
 		winner: Option<AccountId>,
+		finished_at: BlockNumber,
 	) -> Self {
 		FinishedChallenge {
 			challenger: accepted_challenge.challenger,
 			rival: accepted_challenge.rival,
+			asset: accepted_challenge.asset,
 			bet_amount: accepted_challenge.bet_amount,
+			deposit: accepted_challenge.deposit,
+			rounds: accepted_challenge.rounds,
+			score: accepted_challenge.score,
+			hands,
 			winner,
+			finished_at,
+		}
+	}
+
+	/// Returns the hand revealed by the given player, or `None` if it did not take part in the
+	/// challenge.
+	pub fn hand_of(&self, player: &AccountId) -> Option<Option<Hand>> {
+		if self.challenger == *player {
+			Some(self.hands.0.clone())
+		} else if self.rival == *player {
+			Some(self.hands.1.clone())
+		} else {
+			None
 		}
 	}
 }
 
 #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
-pub enum ChallengeState<AccountId: PartialEq + Clone, Balance> {
-	Open(OpenChallenge<AccountId, Balance>),
-	Accepted(AcceptedChallenge<AccountId, Balance>),
-	Finished(FinishedChallenge<AccountId, Balance>),
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId> {
+	Open(OpenChallenge<AccountId, Balance, BlockNumber, AssetId>),
+	Accepted(AcceptedChallenge<AccountId, Balance, BlockNumber, AssetId>),
+	Finished(FinishedChallenge<AccountId, Balance, BlockNumber, Hand, AssetId>),
+}
+
+impl<AccountId: PartialEq + Clone, Balance, BlockNumber, Hand, AssetId>
+	ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>
+{
+	pub fn contains_player(&self, player: &AccountId) -> bool {
+		match self {
+			ChallengeState::Open(open) => open.challenger == *player,
+			ChallengeState::Accepted(accepted) => accepted.contains_player(player),
+			ChallengeState::Finished(finished) => {
+				finished.challenger == *player || finished.rival == *player
+			},
+		}
+	}
 }
 
-#[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+/// Hands of classic rock-paper-scissors, played under `ClassicRules`.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum ChallengePlay {
 	Rock,
 	Paper,
 	Scissors,
 }
 
-impl ChallengePlay {
-	fn as_bytes(&self) -> [u8; 1] {
-		match self {
-			ChallengePlay::Rock => 1_u8.to_ne_bytes(),
-			ChallengePlay::Paper => 2_u8.to_ne_bytes(),
-			ChallengePlay::Scissors => 3_u8.to_ne_bytes(),
-		}
+/// Commitment to a hand played by an account in a given round of a challenge, or in a game against
+/// the house.
+///
+/// The commitment hash is the `blake2_256` of the SCALE encoding of this type, so the variant index
+/// acts as the version of the scheme. Binding the challenge, the round, the player and the genesis
+/// hash of the chain prevents a commitment from being replayed in other challenges or rounds, by
+/// other accounts or on other chains. Hands are encoded as defined by the `GameRules` in use, which
+/// for `ClassicRules` keeps commitments to `ChallengePlay` hands unchanged.
+#[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum PlayCommitment<AccountId, Hash, Hand> {
+	/// Single round commitment, no longer accepted since challenges have several rounds.
+	V1 {
+		salt: ChallengePlaySalt,
+		hand: Hand,
+		challenge_id: ChallengeId,
+		player: AccountId,
+		genesis_hash: Hash,
+	},
+	V2 {
+		salt: ChallengePlaySalt,
+		hand: Hand,
+		challenge_id: ChallengeId,
+		round: ChallengeRound,
+		player: AccountId,
+		genesis_hash: Hash,
+	},
+	/// Commitment to the hand of a game against the house, bound to the house nonce of the player
+	/// as the identifier of the game is only assigned once it starts.
+	House {
+		salt: ChallengePlaySalt,
+		hand: Hand,
+		player: AccountId,
+		nonce: HouseNonce,
+		genesis_hash: Hash,
+	},
+}
+
+impl<AccountId: Encode, Hash: Encode, Hand: Encode> PlayCommitment<AccountId, Hash, Hand> {
+	pub fn new(
+		salt: ChallengePlaySalt,
+		hand: Hand,
+		challenge_id: ChallengeId,
+		round: ChallengeRound,
+		player: AccountId,
+		genesis_hash: Hash,
+	) -> Self {
+		PlayCommitment::V2 { salt, hand, challenge_id, round, player, genesis_hash }
+	}
+
+	pub fn house(
+		salt: ChallengePlaySalt,
+		hand: Hand,
+		player: AccountId,
+		nonce: HouseNonce,
+		genesis_hash: Hash,
+	) -> Self {
+		PlayCommitment::House { salt, hand, player, nonce, genesis_hash }
 	}
 
-	pub fn generate_hash(&self, secret: u64) -> ChallengePlayHash {
-		let mut bytes = sp_std::vec::Vec::new();
-		bytes.extend(self.as_bytes());
-		bytes.extend(secret.to_ne_bytes());
-		sp_io::hashing::twox_64(&bytes)
+	pub fn hash(&self) -> ChallengePlayHash {
+		sp_io::hashing::blake2_256(&self.encode())
 	}
 
-	pub fn compare_hash_with(&self, secret: u64, other_hash: ChallengePlayHash) -> bool {
-		self.generate_hash(secret) == other_hash
+	pub fn verify(&self, play_hash: &ChallengePlayHash) -> bool {
+		self.hash() == *play_hash
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub enum PlayResult {
 	Win,
 	Lose,
 	Draw,
 }
 
-impl ChallengePlay {
-	pub fn beats(&self, other: &ChallengePlay) -> PlayResult {
+impl PlayResult {
+	/// Outcome of the same play from the point of view of the other player.
+	pub fn reverse(&self) -> PlayResult {
 		match self {
-			ChallengePlay::Rock => match other {
-				ChallengePlay::Rock => PlayResult::Draw,
-				ChallengePlay::Paper => PlayResult::Lose,
-				ChallengePlay::Scissors => PlayResult::Win,
+			PlayResult::Win => PlayResult::Lose,
+			PlayResult::Lose => PlayResult::Win,
+			PlayResult::Draw => PlayResult::Draw,
+		}
+	}
+}
+
+/// Progress of a participant through the commit-reveal phases of a challenge.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CommitmentStatus<Hand> {
+	/// The player has not sent its hand commitment yet
+	NotCommitted,
+	/// The player has sent its hand commitment but has not revealed it yet
+	Committed,
+	/// The player has revealed the hand it committed to
+	Revealed(Hand),
+	/// The challenge finished without the player revealing its hand
+	Unrevealed,
+}
+
+/// Record of the challenges an account has finished. Amounts only account for bets placed in the
+/// native currency.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PlayerStatistics<Balance, Hand> {
+	wins: u32,
+	losses: u32,
+	draws: u32,
+	/// Sum of the bets placed in finished challenges.
+	total_wagered: Balance,
+	/// Sum of the bets won from rivals, after the rake. The net profit of the player is the
+	/// difference with `total_lost`.
+	total_won: Balance,
+	/// Sum of the bets lost to rivals.
+	total_lost: Balance,
+	/// Number of challenges won in a row up to the last finished one.
+	current_streak: u32,
+	longest_streak: u32,
+	/// Number of times each hand was revealed in scored rounds, for the hands revealed at least
+	/// once.
+	hands_revealed: Vec<(Hand, u32)>,
+}
+
+impl<Balance: Default, Hand> Default for PlayerStatistics<Balance, Hand> {
+	fn default() -> Self {
+		PlayerStatistics {
+			wins: 0,
+			losses: 0,
+			draws: 0,
+			total_wagered: Default::default(),
+			total_won: Default::default(),
+			total_lost: Default::default(),
+			current_streak: 0,
+			longest_streak: 0,
+			hands_revealed: Vec::new(),
+		}
+	}
+}
+
+impl<Balance: Saturating + Copy, Hand: PartialEq> PlayerStatistics<Balance, Hand> {
+	/// Records the outcome of a finished challenge from the point of view of the player, along
+	/// with the amounts it wagered and won or lost in it.
+	pub fn record_result(&mut self, result: PlayResult, wagered: Balance, amount: Balance) {
+		self.total_wagered = self.total_wagered.saturating_add(wagered);
+		match result {
+			PlayResult::Win => {
+				self.wins = self.wins.saturating_add(1);
+				self.total_won = self.total_won.saturating_add(amount);
+				self.current_streak = self.current_streak.saturating_add(1);
+				self.longest_streak = self.longest_streak.max(self.current_streak);
 			},
-			ChallengePlay::Paper => match other {
-				ChallengePlay::Rock => PlayResult::Win,
-				ChallengePlay::Paper => PlayResult::Draw,
-				ChallengePlay::Scissors => PlayResult::Lose,
+			PlayResult::Lose => {
+				self.losses = self.losses.saturating_add(1);
+				self.total_lost = self.total_lost.saturating_add(amount);
+				self.current_streak = 0;
 			},
-			ChallengePlay::Scissors => match other {
-				ChallengePlay::Rock => PlayResult::Lose,
-				ChallengePlay::Paper => PlayResult::Win,
-				ChallengePlay::Scissors => PlayResult::Draw,
+			PlayResult::Draw => {
+				self.draws = self.draws.saturating_add(1);
+				self.current_streak = 0;
 			},
 		}
 	}
+
+	pub fn record_hand(&mut self, hand: Hand) {
+		match self.hands_revealed.iter_mut().find(|(revealed, _)| *revealed == hand) {
+			Some((_, count)) => *count = count.saturating_add(1),
+			None => self.hands_revealed.push((hand, 1)),
+		}
+	}
+}
+
+impl ChallengePlay {
+	pub fn beats(&self, other: &ChallengePlay) -> PlayResult {
+		ClassicRules::outcome(self, other)
+	}
 }
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use sp_std::ops::Mul;
-
-	use frame_support::traits::{BalanceStatus, Currency, ReservableCurrency};
+	use sp_std::vec::Vec;
+
+	use frame_support::sp_runtime::{
+		traits::{One, Saturating, TrailingZeroInput, Zero},
+		Permill,
+	};
+
+	use frame_support::{
+		sp_runtime::traits::AccountIdConversion,
+		traits::{
+			fungibles, BalanceStatus, Currency, ExistenceRequirement, Imbalance, OnUnbalanced,
+			Randomness, ReservableCurrency,
+		},
+		PalletId,
+	};
 	use frame_system::pallet_prelude::*;
 
-	type BalanceOf<T> =
-		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type BalanceOf<T, I = ()> =
+		<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type NegativeImbalanceOf<T, I = ()> = <<T as Config<I>>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	pub type ChallengeStateOf<T, I = ()> = ChallengeState<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+		<T as frame_system::Config>::BlockNumber,
+		HandOf<T, I>,
+		<T as Config<I>>::AssetId,
+	>;
+
+	pub type HandOf<T, I = ()> = <<T as Config<I>>::GameRules as GameRules>::Hand;
+
+	pub type PlayerStatisticsOf<T, I = ()> = PlayerStatistics<BalanceOf<T, I>, HandOf<T, I>>;
+
+	pub type TournamentOf<T, I = ()> = Tournament<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	pub type SeasonResultOf<T, I = ()> = SeasonResult<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	pub type HouseGameOf<T, I = ()> =
+		HouseGame<BalanceOf<T, I>, <T as frame_system::Config>::BlockNumber, HandOf<T, I>>;
+
+	/// Asset a bet is placed in, `None` standing for the native currency.
+	pub type BetAssetOf<T, I = ()> = Option<<T as Config<I>>::AssetId>;
+
+	/// Bet asset and amount, identifier, challenger and rating band of an open challenge listed
+	/// in the lobby.
+	pub type LobbyEntryOf<T, I = ()> = (
+		BetAssetOf<T, I>,
+		BalanceOf<T, I>,
+		ChallengeId,
+		<T as frame_system::Config>::AccountId,
+		Option<RatingBand>,
+	);
+
+	type AcceptedChallengeOf<T, I = ()> = AcceptedChallenge<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config<I>>::AssetId,
+	>;
+
+	/// Round scored by `score_round`, along with the winner, payout and rake of the challenge if
+	/// the round settled it.
+	struct ScoredRound<T: Config<I>, I: 'static> {
+		challenge_state: AcceptedChallengeOf<T, I>,
+		/// Hands of the challenger and of the rival.
+		hands: (HandOf<T, I>, HandOf<T, I>),
+		round_winner: Option<T::AccountId>,
+		settlement: Option<(Option<T::AccountId>, BalanceOf<T, I>, BalanceOf<T, I>)>,
+	}
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
 
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// Minimum bet in the native currency.
+		#[pallet::constant]
+		type MinBetAmount: Get<BalanceOf<Self, I>>;
+
+		/// Identifier of the assets bets can be placed in besides the native currency.
+		type AssetId: Member + Parameter + Copy + Ord + MaxEncodedLen;
+
+		/// Assets bets can be placed in, sharing the balance type of `Currency`.
+		type Assets: fungibles::Transfer<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = BalanceOf<Self, I>,
+		>;
+
+		/// Identifier of the account holding the bets placed in assets until they are settled.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Account receiving the rake taken from winnings in assets.
+		type AssetFeeCollector: Get<Self::AccountId>;
+
+		/// Number of blocks both players have to play once a challenge has been accepted.
+		#[pallet::constant]
+		type PlayTimeout: Get<Self::BlockNumber>;
+
+		/// Number of blocks both players have to reveal once both of them have played.
+		#[pallet::constant]
+		type RevealTimeout: Get<Self::BlockNumber>;
+
+		/// Storage deposit reserved from the creator of a challenge, paid to whoever prunes the
+		/// challenge once it has finished.
+		#[pallet::constant]
+		type ChallengeDeposit: Get<BalanceOf<Self, I>>;
+
+		/// Number of blocks a finished challenge is kept in storage before it can be pruned.
+		#[pallet::constant]
+		type FinishedRetention: Get<Self::BlockNumber>;
+
+		/// Maximum number of open or accepted challenges an account can take part in at once.
+		#[pallet::constant]
+		type MaxActiveChallengesPerAccount: Get<u32>;
+
+		/// Maximum number of open challenges listed in the lobby. Challenges created while it is full
+		/// are not listed.
+		#[pallet::constant]
+		type MaxLobbySize: Get<u32>;
+
+		/// Rules of the game played in challenges.
+		type GameRules: GameRules;
+
+		/// Share of the bet of the loser kept as a fee when a challenge is won. Draws are not
+		/// charged.
+		#[pallet::constant]
+		type RakePercent: Get<Permill>;
+
+		/// Handler for the fees taken from the winnings.
+		type FeeDestination: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+
+		/// Rating of the players who have not finished any challenge yet.
+		#[pallet::constant]
+		type StartingRating: Get<Rating>;
+
+		/// Maximum number of rating points a player can win or lose in a challenge.
+		#[pallet::constant]
+		type KFactor: Get<Rating>;
+
+		/// Maximum number of players of a tournament.
+		#[pallet::constant]
+		type MaxTournamentPlayers: Get<u32>;
+
+		/// Maximum number of places sharing the prize pool of a tournament.
+		#[pallet::constant]
+		type MaxPrizeTiers: Get<u32>;
+
+		/// Maximum number of tournaments registering players or being played at once, which
+		/// bounds the number of tournament rounds seeded in a block.
+		#[pallet::constant]
+		type MaxActiveTournaments: Get<u32>;
+
+		/// Deposit reserved from the organizer of a tournament, returned once the tournament
+		/// finishes or is cancelled.
+		#[pallet::constant]
+		type TournamentDeposit: Get<BalanceOf<Self, I>>;
+
+		/// Maximum number of blocks between the creation of a tournament and the end of its
+		/// registration.
+		#[pallet::constant]
+		type MaxRegistrationPeriod: Get<Self::BlockNumber>;
+
+		/// Number of blocks of a season, seasons ending at every multiple of it.
 		#[pallet::constant]
-		type MinBetAmount: Get<BalanceOf<Self>>;
+		type SeasonLength: Get<Self::BlockNumber>;
+
+		/// Number of players ranked in the leaderboard of a season.
+		#[pallet::constant]
+		type LeaderboardSize: Get<u32>;
+
+		/// Share of the season pool paid out to each place of the leaderboard at the end of a
+		/// season, best place first. Places beyond `LeaderboardSize` are never paid.
+		#[pallet::constant]
+		type SeasonPrizeShares: Get<Vec<Permill>>;
+
+		/// Share of the rake added to the season pool instead of being handed to
+		/// `FeeDestination`, once the pallet account exists. Rakes taken in assets are not shared.
+		#[pallet::constant]
+		type SeasonRakeShare: Get<Permill>;
+
+		/// Source of the hands of the house, drawn in the block after the player committed to its
+		/// hand so that the commitment cannot depend on them. The hash of the block including the
+		/// commitment is mixed in, as the randomness may only cover the blocks before it.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// Share of the bankroll of the house the bets of the pending house games can add up to.
+		#[pallet::constant]
+		type MaxHouseExposure: Get<Permill>;
+
+		/// Maximum number of house games started in a block, which bounds the number of house
+		/// hands drawn in the next one.
+		#[pallet::constant]
+		type MaxHouseGamesPerBlock: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
+	/// Version of the storage layout, see `migrations`.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
-	pub struct Pallet<T>(_);
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::storage]
 	#[pallet::getter(fn next_challenge_id)]
-	pub type NextBetId<T> = StorageValue<_, ChallengeId, ValueQuery>;
+	pub type NextBetId<T: Config<I>, I: 'static = ()> = StorageValue<_, ChallengeId, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn challenge_store)]
-	pub type ChallengeStore<T: Config> =
-		StorageMap<_, Blake2_128Concat, ChallengeId, ChallengeState<T::AccountId, BalanceOf<T>>>;
+	pub type ChallengeStore<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ChallengeId, ChallengeStateOf<T, I>>;
 
+	/// Hand commitments of the players, by challenge and round.
 	#[pallet::storage]
 	#[pallet::getter(fn challenge_plays_store)]
-	pub type ChallengePlaysStore<T: Config> = StorageDoubleMap<
+	pub type ChallengePlaysStore<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ChallengeId>,
+			NMapKey<Blake2_128Concat, ChallengeRound>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		ChallengePlayHash,
+	>;
+
+	/// Hands revealed by the players, by challenge and round.
+	#[pallet::storage]
+	#[pallet::getter(fn challenge_reveals_store)]
+	pub type ChallengeRevealsStore<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ChallengeId>,
+			NMapKey<Blake2_128Concat, ChallengeRound>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		HandOf<T, I>,
+	>;
+
+	/// Challenges each account has created or entered, kept until the challenge is removed.
+	#[pallet::storage]
+	#[pallet::getter(fn player_challenges)]
+	pub type PlayerChallenges<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, ChallengeId, ()>;
+
+	/// Number of open or accepted challenges each account takes part in.
+	#[pallet::storage]
+	#[pallet::getter(fn active_challenge_count)]
+	pub type ActiveChallengeCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Open challenges sorted by bet asset, bet amount and identifier, along with their challenger.
+	#[pallet::storage]
+	#[pallet::getter(fn lobby)]
+	pub type Lobby<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<LobbyEntryOf<T, I>, T::MaxLobbySize>, ValueQuery>;
+
+	/// Player waiting in the matchmaking queue of each bet asset and amount, along with its
+	/// storage deposit and the block it joined at. A tier holds a single player, as the next one
+	/// to join is matched right away.
+	#[pallet::storage]
+	#[pallet::getter(fn matchmaking_queue)]
+	pub type MatchmakingQueue<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Blake2_128Concat,
-		ChallengeId,
+		(BetAssetOf<T, I>, BalanceOf<T, I>),
+		(T::AccountId, BalanceOf<T, I>, <T as frame_system::Config>::BlockNumber),
+	>;
+
+	/// Minimum bet of each asset bets can be placed in. Bets in assets without one are refused.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_min_bet)]
+	pub type AssetMinBet<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BalanceOf<T, I>>;
+
+	#[pallet::type_value]
+	pub fn DefaultRating<T: Config<I>, I: 'static>() -> Rating {
+		T::StartingRating::get()
+	}
+
+	/// Elo rating of each player, updated whenever one of their challenges finishes.
+	#[pallet::storage]
+	#[pallet::getter(fn rating)]
+	pub type Ratings<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Rating, ValueQuery, DefaultRating<T, I>>;
+
+	/// Statistics of each account over the challenges it has finished.
+	#[pallet::storage]
+	#[pallet::getter(fn player_stats)]
+	pub type PlayerStats<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, PlayerStatisticsOf<T, I>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_tournament_id)]
+	pub type NextTournamentId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, TournamentId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tournament)]
+	pub type Tournaments<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, TournamentId, TournamentOf<T, I>>;
+
+	/// Number of tournaments registering players or being played.
+	#[pallet::storage]
+	#[pallet::getter(fn active_tournament_count)]
+	pub type ActiveTournamentCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// Players registered to each tournament, in registration order.
+	#[pallet::storage]
+	#[pallet::getter(fn tournament_players)]
+	pub type TournamentPlayers<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
 		Blake2_128Concat,
-		T::AccountId,
-		ChallengePlayHash,
+		TournamentId,
+		BoundedVec<T::AccountId, T::MaxTournamentPlayers>,
+		ValueQuery,
+	>;
+
+	/// Players advancing from the round being played in each tournament, by match. Slots are
+	/// filled as matches finish, the next round being seeded once all of them are.
+	#[pallet::storage]
+	#[pallet::getter(fn tournament_bracket)]
+	pub type TournamentBracket<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TournamentId,
+		BoundedVec<Option<T::AccountId>, T::MaxTournamentPlayers>,
+		ValueQuery,
+	>;
+
+	/// Players knocked out of each tournament, by round.
+	#[pallet::storage]
+	#[pallet::getter(fn tournament_eliminations)]
+	pub type TournamentEliminations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TournamentId,
+		Blake2_128Concat,
+		TournamentRound,
+		BoundedVec<T::AccountId, T::MaxTournamentPlayers>,
+		ValueQuery,
+	>;
+
+	/// Tournament and bracket slot of the challenges played as tournament matches.
+	#[pallet::storage]
+	#[pallet::getter(fn tournament_match)]
+	pub type TournamentMatches<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ChallengeId, (TournamentId, u32)>;
+
+	/// Tournaments whose registration closes or whose next round starts at each block. A
+	/// tournament is scheduled at most once at a time, so a block cannot hold more of them than
+	/// `MaxActiveTournaments`.
+	#[pallet::storage]
+	#[pallet::getter(fn tournament_schedule)]
+	pub type TournamentSchedule<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<TournamentId, T::MaxActiveTournaments>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn current_season)]
+	pub type CurrentSeason<T: Config<I>, I: 'static = ()> = StorageValue<_, SeasonId, ValueQuery>;
+
+	/// Points of each account along with the season they were won in. Points of past seasons
+	/// count as none.
+	#[pallet::storage]
+	#[pallet::getter(fn season_points)]
+	pub type PlayerSeasonPoints<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (SeasonId, SeasonPoints), ValueQuery>;
+
+	/// Players with the most points in the current season, best first.
+	#[pallet::storage]
+	#[pallet::getter(fn leaderboard)]
+	pub type Leaderboard<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<(T::AccountId, SeasonPoints), T::LeaderboardSize>, ValueQuery>;
+
+	/// Funds paid out to the leaderboard at the end of the current season, held by the pallet
+	/// account.
+	#[pallet::storage]
+	#[pallet::getter(fn season_pool)]
+	pub type SeasonPool<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BalanceOf<T, I>, ValueQuery>;
+
+	/// Final standings and prizes of the past seasons.
+	#[pallet::storage]
+	#[pallet::getter(fn season_result)]
+	pub type SeasonResults<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, SeasonId, SeasonResultOf<T, I>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_house_game_id)]
+	pub type NextHouseGameId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, HouseGameId, ValueQuery>;
+
+	/// Number of games each account has started against the house.
+	#[pallet::storage]
+	#[pallet::getter(fn house_nonce)]
+	pub type HouseNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, HouseNonce, ValueQuery>;
+
+	/// Pending game of each account against the house.
+	#[pallet::storage]
+	#[pallet::getter(fn house_game)]
+	pub type HouseGames<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, HouseGameOf<T, I>>;
+
+	/// Sum of the bets of the pending house games, which the house would pay if it lost them all.
+	#[pallet::storage]
+	#[pallet::getter(fn house_exposure)]
+	pub type HouseExposure<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BalanceOf<T, I>, ValueQuery>;
+
+	/// Players whose house game gets its house hand drawn at each block.
+	#[pallet::storage]
+	#[pallet::getter(fn house_draws)]
+	pub type HouseDraws<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<T::AccountId, T::MaxHouseGamesPerBlock>,
+		ValueQuery,
 	>;
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		/// Migrates the storage from the unversioned layout of the first release.
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T, I>()
+		}
+
+		/// Ends the current season at every multiple of `SeasonLength`, draws the hands of the
+		/// house games started in the previous block, then seeds the brackets of the tournaments
+		/// whose registration closed in the previous block and the rounds of those whose previous
+		/// round was completed.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let season_length = T::SeasonLength::get();
+			let mut weight = if !season_length.is_zero() && (now % season_length).is_zero() {
+				Self::end_season(now)
+			} else {
+				0
+			};
+
+			let draws = HouseDraws::<T, I>::take(now).into_inner();
+			weight = weight.saturating_add(T::WeightInfo::draw_house_hands(draws.len() as u32));
+			for player in draws {
+				Self::draw_house_hand(&player, now);
+			}
+
+			TournamentSchedule::<T, I>::take(now).into_inner().into_iter().fold(
+				weight.saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+				|weight, tournament_id| weight.saturating_add(Self::seed_round(tournament_id)),
+			)
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// Triggered when a new challenge has been created. [challenge_id, creator_id, bet_amount]
-		ChallengeCreated(ChallengeId, T::AccountId, BalanceOf<T>),
-		/// Triggered when an account accepts a challenge. [challenge_id, challenger_id]
-		EnteredChallenge(ChallengeId, T::AccountId),
-		/// Triggered when an account plays in a certain challenge. [challenge_id, challenger_id]
-		PlayedInChallenge(ChallengeId, T::AccountId),
-		/// Triggered when both players have sent their play on a given challenge. [challenge_id]
-		ChallengeReadyForReveal(ChallengeId),
-		/// Triggered when a challenge has been finished. [winner_id]
-		ChallengeFinished(Option<T::AccountId>),
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Triggered when a new challenge has been created, along with the rival it is addressed
+		/// to if it is a direct challenge.
+		ChallengeCreated {
+			challenge_id: ChallengeId,
+			challenger: T::AccountId,
+			intended_rival: Option<T::AccountId>,
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+			rounds: u8,
+		},
+		/// Triggered when the creator of an open challenge withdraws it, releasing its bet.
+		ChallengeCancelled {
+			challenge_id: ChallengeId,
+			challenger: T::AccountId,
+			bet_amount: BalanceOf<T, I>,
+		},
+		/// Triggered when an account accepts a challenge.
+		EnteredChallenge {
+			challenge_id: ChallengeId,
+			challenger: T::AccountId,
+			rival: T::AccountId,
+			bet_amount: BalanceOf<T, I>,
+		},
+		/// Triggered when an account plays in a round of a certain challenge.
+		PlayedInChallenge {
+			challenge_id: ChallengeId,
+			round: ChallengeRound,
+			player: T::AccountId,
+			play_hash: ChallengePlayHash,
+		},
+		/// Triggered when both players have sent their play in a round of a given challenge, along
+		/// with the block from which the reveal phase can be timed out.
+		ChallengeReadyForReveal {
+			challenge_id: ChallengeId,
+			round: ChallengeRound,
+			reveal_deadline: T::BlockNumber,
+		},
+		/// Triggered when a player has revealed its hand in a round of a challenge.
+		PlayRevealed {
+			challenge_id: ChallengeId,
+			round: ChallengeRound,
+			player: T::AccountId,
+			hand: HandOf<T, I>,
+		},
+		/// Triggered when both hands of a round have been revealed, along with the winner of the
+		/// round, if it was not a draw, and the score of the challenger and the rival.
+		RoundFinished {
+			challenge_id: ChallengeId,
+			round: ChallengeRound,
+			challenger_hand: HandOf<T, I>,
+			rival_hand: HandOf<T, I>,
+			winner: Option<T::AccountId>,
+			score: (u8, u8),
+		},
+		/// Triggered when a challenge has been finished. Hands are the ones of the last round and
+		/// are missing for players that did not reveal them. The payout is the amount the winner
+		/// took from the loser, after the rake was taken from it as a fee.
+		ChallengeFinished {
+			challenge_id: ChallengeId,
+			challenger: T::AccountId,
+			rival: T::AccountId,
+			challenger_hand: Option<HandOf<T, I>>,
+			rival_hand: Option<HandOf<T, I>>,
+			winner: Option<T::AccountId>,
+			payout: BalanceOf<T, I>,
+			rake: BalanceOf<T, I>,
+		},
+		/// Triggered when a challenge has been closed due to a phase deadline, along with the
+		/// players that did not do their part in time.
+		ChallengeTimedOut { challenge_id: ChallengeId, forfeited: Vec<T::AccountId> },
+		/// Triggered when a finished challenge has been removed from storage, rewarding the
+		/// account that pruned it with the storage deposit of the challenge.
+		ChallengePruned { challenge_id: ChallengeId, pruner: T::AccountId, reward: BalanceOf<T, I> },
+		/// Triggered when a player starts waiting in the matchmaking queue of a bet asset and
+		/// amount.
+		QueueJoined { player: T::AccountId, asset: BetAssetOf<T, I>, bet_amount: BalanceOf<T, I> },
+		/// Triggered when a player leaves the matchmaking queue without being matched.
+		QueueLeft { player: T::AccountId, asset: BetAssetOf<T, I>, bet_amount: BalanceOf<T, I> },
+		/// Triggered when the minimum bet of an asset has been set, or removed to stop accepting
+		/// bets in it.
+		AssetMinBetSet { asset_id: T::AssetId, min_bet: Option<BalanceOf<T, I>> },
+		/// Triggered when a tournament is organized, opening its registration.
+		TournamentCreated {
+			tournament_id: TournamentId,
+			organizer: T::AccountId,
+			entry_fee: BalanceOf<T, I>,
+			max_players: u32,
+			registration_deadline: <T as frame_system::Config>::BlockNumber,
+		},
+		/// Triggered when a player registers to a tournament, paying its entry fee into the
+		/// prize pool.
+		TournamentJoined { tournament_id: TournamentId, player: T::AccountId },
+		/// Triggered when the matches of a tournament round have been created.
+		TournamentRoundStarted { tournament_id: TournamentId, round: TournamentRound, players: u32 },
+		/// Triggered when a tournament match has been created between two players.
+		TournamentMatchCreated {
+			tournament_id: TournamentId,
+			round: TournamentRound,
+			challenge_id: ChallengeId,
+			challenger: T::AccountId,
+			rival: T::AccountId,
+		},
+		/// Triggered when the last match of a tournament has been played, along with the prizes
+		/// paid out of its pool.
+		TournamentFinished {
+			tournament_id: TournamentId,
+			champion: T::AccountId,
+			prizes: Vec<(T::AccountId, BalanceOf<T, I>)>,
+		},
+		/// Triggered when the registration of a tournament closes with too few players, whose
+		/// entry fees are refunded.
+		TournamentCancelled { tournament_id: TournamentId },
+		/// Triggered when funds have been added to the pool of the current season.
+		SeasonPoolFunded { source: T::AccountId, amount: BalanceOf<T, I> },
+		/// Triggered when a season has ended, along with the prizes paid out of its pool. What
+		/// was not paid out is carried over to the next season.
+		SeasonEnded { season: SeasonId, prizes: Vec<(T::AccountId, BalanceOf<T, I>)> },
+		/// Triggered when a player commits to a hand against the house, whose hand is drawn in
+		/// the next block.
+		HouseGameStarted { game_id: HouseGameId, player: T::AccountId, bet_amount: BalanceOf<T, I> },
+		/// Triggered when the hand of the house has been drawn, opening the reveal phase of the
+		/// player.
+		HouseHandDrawn { game_id: HouseGameId, player: T::AccountId, house_hand: HandOf<T, I> },
+		/// Triggered when a house game has been settled, along with its outcome for the player.
+		/// The hand of the player is missing if it did not reveal it, and the hand of the house if
+		/// it could not be drawn.
+		HouseGameFinished {
+			game_id: HouseGameId,
+			player: T::AccountId,
+			hand: Option<HandOf<T, I>>,
+			house_hand: Option<HandOf<T, I>>,
+			result: PlayResult,
+		},
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		/// The bet amount is not inferior to the minimum bet value
 		InsufficientBetAmount,
 		/// The challenge identifier could not be located
@@ -222,215 +1038,2031 @@ pub mod pallet {
 		ChallengeStateForbidsResolution,
 		/// The challenge logic reached an invalid state
 		InvalidState,
+		/// The account cannot cover the bet amount of the challenge
+		InsufficientFunds,
 		/// Cannot reveal a challenge in which the account is not a participant
 		CannotRevealNonParticipatingChallenge,
 		/// The hash of the original account play and the value indicated in the reveal don't match
 		InvalidHandHash,
+		/// Cannot reveal a play until both players have played in the challenge
+		ChallengeNotReadyForReveal,
+		/// Cannot reveal again an already revealed play
+		ChallengeAlreadyRevealed,
+		/// The deadline of the current challenge phase has not been reached yet
+		ChallengeNotTimedOut,
+		/// Only the account that created a challenge can cancel it
+		CannotCancelOthersChallenge,
+		/// The challenge has already been accepted or finished, so it cannot be cancelled
+		CannotCancelNonOpenChallenge,
+		/// Only finished challenges can be pruned
+		CannotPruneUnfinishedChallenge,
+		/// The challenge finished too recently to be pruned
+		ChallengeRetentionNotElapsed,
+		/// The account already takes part in the maximum number of active challenges
+		TooManyActiveChallenges,
+		/// There is no open challenge in the lobby within the given bet amount
+		NoMatchingChallenge,
+		/// The challenge is addressed to another rival
+		NotIntendedRival,
+		/// Challenges are played to 1, 3, 5 or 7 rounds
+		InvalidRoundCount,
+		/// The account is not waiting in the matchmaking queue of the given bet amount
+		NotInQueue,
+		/// The hand is not part of the move set of the game rules
+		InvalidHand,
+		/// Bets cannot be placed in the given asset
+		AssetNotAccepted,
+		/// The minimum rating of the band is above its maximum rating
+		InvalidRatingBand,
+		/// The rating of the account is outside the rating band of the challenge
+		RatingOutOfBand,
+		/// The tournament identifier could not be located
+		TournamentNotFound,
+		/// Tournaments are played by at least 2 and at most `MaxTournamentPlayers` players
+		InvalidPlayerCap,
+		/// The registration deadline of a tournament must be in the future, within
+		/// `MaxRegistrationPeriod` blocks
+		InvalidRegistrationDeadline,
+		/// Prize shares must be given for 1 to `MaxPrizeTiers` places and add up to 100%
+		InvalidPrizeDistribution,
+		/// No more tournaments can be organized until one of the active ones ends
+		TooManyActiveTournaments,
+		/// The registration of the tournament is closed
+		RegistrationClosed,
+		/// The account is already registered to the tournament
+		AlreadyRegistered,
+		/// The tournament has reached its player cap
+		TournamentFull,
+		/// The account already has a pending game against the house
+		HouseGameInProgress,
+		/// The house cannot cover more bets until some of its pending games are settled
+		HouseExposureExceeded,
+		/// No more house games can be started in this block
+		HouseBusy,
+		/// The account has no pending game against the house
+		HouseGameNotFound,
+		/// The hand of the house is drawn in the block after the game started
+		HouseHandNotDrawn,
 	}
 
-	impl<T> From<DispatchError> for Error<T> {
-		fn from(_: DispatchError) -> Self {
-			Self::InvalidState
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Creates a challenge listed in the lobby, which only rivals rated within `rating_band`
+		/// can enter when one is given. The challenge is left out of the lobby if it is full, but
+		/// can still be entered with `enter_challenge`.
+		#[pallet::weight(T::WeightInfo::create_challenge())]
+		pub fn create_challenge(
+			origin: OriginFor<T>,
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+			rounds: u8,
+			rating_band: Option<RatingBand>,
+		) -> DispatchResult {
+			let challenger = ensure_signed(origin)?;
+
+			ensure!(
+				rating_band.as_ref().map_or(true, RatingBand::is_valid),
+				Error::<T, I>::InvalidRatingBand
+			);
+
+			Ok(Self::do_create_challenge(challenger, None, rating_band, asset, bet_amount, rounds)?)
 		}
-	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		#[pallet::weight(10_000)]
-		pub fn create_challenge(origin: OriginFor<T>, bet_amount: BalanceOf<T>) -> DispatchResult {
+		/// Creates a challenge that only the given rival can enter. Direct challenges are not
+		/// listed in the lobby.
+		#[pallet::weight(T::WeightInfo::create_challenge_for())]
+		pub fn create_challenge_for(
+			origin: OriginFor<T>,
+			rival: T::AccountId,
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+			rounds: u8,
+		) -> DispatchResult {
 			let challenger = ensure_signed(origin)?;
 
-			let min_amount = T::MinBetAmount::get();
-			ensure!(bet_amount >= min_amount, Error::<T>::InsufficientBetAmount);
+			ensure!(challenger != rival, Error::<T, I>::CannotChallengeOneself);
+
+			Ok(Self::do_create_challenge(challenger, Some(rival), None, asset, bet_amount, rounds)?)
+		}
+
+		#[pallet::weight(T::WeightInfo::cancel_challenge())]
+		pub fn cancel_challenge(origin: OriginFor<T>, challenge_id: ChallengeId) -> DispatchResult {
+			let challenger = ensure_signed(origin)?;
 
-			let challenge_id = NextBetId::<T>::get();
-			let challenge_state =
-				ChallengeState::Open(OpenChallenge { challenger: challenger.clone(), bet_amount });
+			let challenge = ChallengeStore::<T, I>::get(&challenge_id)
+				.ok_or(Error::<T, I>::ChallengeNotFound)?;
 
-			NextBetId::<T>::mutate(|x| *x += 1);
+			if let ChallengeState::Open(open_state) = challenge {
+				ensure!(
+					open_state.challenger == challenger,
+					Error::<T, I>::CannotCancelOthersChallenge
+				);
 
-			ChallengeStore::<T>::insert(&challenge_id, challenge_state);
+				Self::release_bet(
+					&challenger,
+					open_state.asset,
+					open_state.bet_amount,
+					open_state.deposit,
+				);
+				ChallengeStore::<T, I>::remove(&challenge_id);
+				Self::release_challenge(&challenger);
+				PlayerChallenges::<T, I>::remove(&challenger, challenge_id);
+				Self::remove_from_lobby(open_state.asset, open_state.bet_amount, challenge_id);
 
-			Self::deposit_event(Event::ChallengeCreated(challenge_id, challenger, bet_amount));
+				Self::deposit_event(Event::ChallengeCancelled {
+					challenge_id,
+					challenger,
+					bet_amount: open_state.bet_amount,
+				});
 
-			Ok(())
+				Ok(())
+			} else {
+				Err(Error::<T, I>::CannotCancelNonOpenChallenge.into())
+			}
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::enter_challenge())]
 		pub fn enter_challenge(origin: OriginFor<T>, challenge_id: ChallengeId) -> DispatchResult {
 			let rival = ensure_signed(origin)?;
 
-			Ok(ChallengeStore::<T>::try_mutate(&challenge_id, |challenge_entry| {
-				ensure!(challenge_entry.is_some(), Error::<T>::ChallengeNotFound);
+			Ok(Self::do_enter_challenge(rival, challenge_id)?)
+		}
 
-				let challenge_state = challenge_entry.as_mut().unwrap();
+		/// Enters the open challenge of the lobby in the given asset with the highest bet not
+		/// above `max_bet`, picking the oldest one among equal bets. Challenges with a rating band
+		/// the account is not rated within are skipped.
+		#[pallet::weight(T::WeightInfo::quick_join())]
+		pub fn quick_join(
+			origin: OriginFor<T>,
+			asset: BetAssetOf<T, I>,
+			max_bet: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let rival = ensure_signed(origin)?;
 
-				if let ChallengeState::Open(open_state) = challenge_state {
-					if open_state.challenger == rival {
-						Err(Error::<T>::CannotChallengeOneself)
-					} else {
-						*challenge_state = ChallengeState::Accepted(AcceptedChallenge::from_open(
-							open_state.clone(),
-							rival.clone(),
-						));
-						Self::deposit_event(Event::EnteredChallenge(challenge_id, rival));
-						Ok(())
-					}
-				} else {
-					Err(Error::<T>::ChallengeNotOpen)
-				}
-			})?)
+			let rating = Ratings::<T, I>::get(&rival);
+			let lobby = Lobby::<T, I>::get();
+			let affordable = lobby.partition_point(|(bet_asset, bet_amount, _, _, _)| {
+				(bet_asset, bet_amount) <= (&asset, &max_bet)
+			});
+			let (_, _, challenge_id, _, _) = lobby[..affordable]
+				.iter()
+				.filter(|(bet_asset, _, _, challenger, rating_band)| {
+					*bet_asset == asset
+						&& *challenger != rival
+						&& rating_band.map_or(true, |band| band.contains(rating))
+				})
+				.max_by(|(_, bet_a, id_a, _, _), (_, bet_b, id_b, _, _)| {
+					bet_a.cmp(bet_b).then_with(|| id_b.cmp(id_a))
+				})
+				.ok_or(Error::<T, I>::NoMatchingChallenge)?;
+
+			Ok(Self::do_enter_challenge(rival, *challenge_id)?)
 		}
 
-		// play
+		/// Waits for a rival in the matchmaking queue of the given bet asset and amount, or
+		/// starts an accepted single round challenge against the player already waiting in it.
+		#[pallet::weight(T::WeightInfo::join_queue())]
+		pub fn join_queue(
+			origin: OriginFor<T>,
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+
+			Self::ensure_valid_bet(&asset, bet_amount)?;
+
+			if let Some((challenger, deposit, queued_at)) =
+				MatchmakingQueue::<T, I>::get((asset, bet_amount))
+			{
+				ensure!(challenger != player, Error::<T, I>::CannotChallengeOneself);
+				Self::ensure_can_join(&player)?;
+				Self::escrow_bet(&player, asset, bet_amount, Zero::zero())?;
+
+				MatchmakingQueue::<T, I>::remove((asset, bet_amount));
+
+				let challenge_id = NextBetId::<T, I>::get();
+				NextBetId::<T, I>::mutate(|x| *x += 1);
+
+				// The waiting player already counts the challenge as active since it joined
+				PlayerChallenges::<T, I>::insert(&challenger, challenge_id, ());
+				Self::track_challenge(&player, challenge_id);
+
+				let open_challenge = OpenChallenge {
+					challenger: challenger.clone(),
+					asset,
+					bet_amount,
+					deposit,
+					intended_rival: None,
+					rating_band: None,
+					rounds: 1,
+					created_at: queued_at,
+				};
+				ChallengeStore::<T, I>::insert(
+					&challenge_id,
+					ChallengeState::Accepted(AcceptedChallenge::from_open(
+						open_challenge,
+						player.clone(),
+						frame_system::Pallet::<T>::block_number(),
+					)),
+				);
+
+				Self::deposit_event(Event::ChallengeCreated {
+					challenge_id,
+					challenger: challenger.clone(),
+					intended_rival: None,
+					asset,
+					bet_amount,
+					rounds: 1,
+				});
+				Self::deposit_event(Event::EnteredChallenge {
+					challenge_id,
+					challenger,
+					rival: player,
+					bet_amount,
+				});
+			} else {
+				Self::ensure_can_join(&player)?;
+				let deposit = T::ChallengeDeposit::get();
+				Self::escrow_bet(&player, asset, bet_amount, deposit)?;
+
+				MatchmakingQueue::<T, I>::insert(
+					(asset, bet_amount),
+					(player.clone(), deposit, frame_system::Pallet::<T>::block_number()),
+				);
+				ActiveChallengeCount::<T, I>::mutate(&player, |count| {
+					*count = count.saturating_add(1)
+				});
+
+				Self::deposit_event(Event::QueueJoined { player, asset, bet_amount });
+			}
+
+			Ok(())
+		}
+
+		/// Leaves the matchmaking queue of the given bet asset and amount, releasing the stake
+		/// held in escrow.
+		#[pallet::weight(T::WeightInfo::leave_queue())]
+		pub fn leave_queue(
+			origin: OriginFor<T>,
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+
+			let deposit = match MatchmakingQueue::<T, I>::get((asset, bet_amount)) {
+				Some((queued, deposit, _)) if queued == player => deposit,
+				_ => return Err(Error::<T, I>::NotInQueue.into()),
+			};
+
+			MatchmakingQueue::<T, I>::remove((asset, bet_amount));
+			Self::release_bet(&player, asset, bet_amount, deposit);
+			Self::release_challenge(&player);
+
+			Self::deposit_event(Event::QueueLeft { player, asset, bet_amount });
+
+			Ok(())
+		}
+
+		/// Sets the minimum bet of an asset, or removes it to stop accepting new bets in the
+		/// asset. Challenges already placed in the asset are not affected.
+		#[pallet::weight(T::WeightInfo::set_asset_min_bet())]
+		pub fn set_asset_min_bet(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			min_bet: Option<BalanceOf<T, I>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			match min_bet {
+				Some(min_bet) => AssetMinBet::<T, I>::insert(asset_id, min_bet),
+				None => AssetMinBet::<T, I>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::AssetMinBetSet { asset_id, min_bet });
+
+			Ok(())
+		}
+
+		/// Moves funds from the `source` account to the pool of the current season.
+		#[pallet::weight(T::WeightInfo::fund_season_pool())]
+		pub fn fund_season_pool(
+			origin: OriginFor<T>,
+			source: T::AccountId,
+			amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			T::Currency::transfer(
+				&source,
+				&Self::account_id(),
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			SeasonPool::<T, I>::mutate(|pool| *pool = pool.saturating_add(amount));
+
+			Self::deposit_event(Event::SeasonPoolFunded { source, amount });
+
+			Ok(())
+		}
+
+		/// Starts a single round game against the house, committing to a hand with
+		/// `PlayCommitment::house` bound to the current house nonce of the account. The house
+		/// matches the bet and draws its hand in the next block, after which the player has
+		/// `RevealTimeout` blocks to reveal its hand with `reveal_house`.
+		#[pallet::weight(T::WeightInfo::play_house())]
+		pub fn play_house(
+			origin: OriginFor<T>,
+			hand_commitment: ChallengePlayHash,
+			bet_amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+
+			ensure!(!HouseGames::<T, I>::contains_key(&player), Error::<T, I>::HouseGameInProgress);
+			Self::ensure_valid_bet(&None, bet_amount)?;
+
+			let bankroll = T::Currency::free_balance(&Self::house_account_id());
+			let exposure = HouseExposure::<T, I>::get().saturating_add(bet_amount);
+			ensure!(
+				exposure <= T::MaxHouseExposure::get() * bankroll,
+				Error::<T, I>::HouseExposureExceeded
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let draw_at = now.saturating_add(One::one());
+			let mut draws = HouseDraws::<T, I>::get(draw_at);
+			draws.try_push(player.clone()).map_err(|_| Error::<T, I>::HouseBusy)?;
+
+			T::Currency::reserve(&player, bet_amount)
+				.map_err(|_| Error::<T, I>::InsufficientFunds)?;
+
+			let game_id = NextHouseGameId::<T, I>::get();
+			NextHouseGameId::<T, I>::put(game_id.saturating_add(1));
+			let nonce = HouseNonces::<T, I>::get(&player);
+			HouseNonces::<T, I>::insert(&player, nonce.saturating_add(1));
+			HouseDraws::<T, I>::insert(draw_at, draws);
+			HouseExposure::<T, I>::put(exposure);
+			HouseGames::<T, I>::insert(
+				&player,
+				HouseGame {
+					game_id,
+					nonce,
+					bet_amount,
+					commitment: hand_commitment,
+					played_at: now,
+					house_hand: None,
+				},
+			);
+
+			Self::deposit_event(Event::HouseGameStarted { game_id, player, bet_amount });
+
+			Ok(())
+		}
+
+		/// Reveals the hand committed to against the house, settling the game.
+		#[pallet::weight(T::WeightInfo::reveal_house())]
+		pub fn reveal_house(
+			origin: OriginFor<T>,
+			hand: HandOf<T, I>,
+			salt: ChallengePlaySalt,
+		) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+
+			let game = HouseGames::<T, I>::get(&player).ok_or(Error::<T, I>::HouseGameNotFound)?;
+			let (house_hand, _) =
+				game.house_hand.clone().ok_or(Error::<T, I>::HouseHandNotDrawn)?;
+
+			ensure!(T::GameRules::is_valid(&hand), Error::<T, I>::InvalidHand);
+			ensure!(
+				Self::verify_house_play(game.nonce, &player, &hand, salt, &game.commitment),
+				Error::<T, I>::InvalidHandHash
+			);
+
+			let result = T::GameRules::outcome(&hand, &house_hand);
+			Self::settle_house_game(player, game, Some(hand), Some(house_hand), result)
+		}
+
+		/// Closes the house game of `player` once its reveal deadline has passed, the player
+		/// losing its bet to the house. The bet is given back if the hand of the house could not
+		/// be drawn.
+		#[pallet::weight(T::WeightInfo::claim_house_timeout())]
+		pub fn claim_house_timeout(origin: OriginFor<T>, player: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let game = HouseGames::<T, I>::get(&player).ok_or(Error::<T, I>::HouseGameNotFound)?;
+			let (house_hand, reveal_started_at) = match game.house_hand.clone() {
+				Some((house_hand, drawn_at)) => (Some(house_hand), drawn_at),
+				None => (None, game.played_at.saturating_add(One::one())),
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number()
+					>= reveal_started_at.saturating_add(T::RevealTimeout::get()),
+				Error::<T, I>::ChallengeNotTimedOut
+			);
+
+			let result = if house_hand.is_some() { PlayResult::Lose } else { PlayResult::Draw };
+			Self::settle_house_game(player, game, None, house_hand, result)
+		}
+
+		/// Organizes a single elimination tournament. Players register until
+		/// `registration_deadline` by paying `entry_fee` into the prize pool, which is shared by
+		/// the best places according to `prize_shares`: the first share goes to the champion, the
+		/// second one to the runner-up, the third one to the semi-finalists and so on.
+		///
+		/// `TournamentDeposit` is reserved from the organizer until the tournament ends.
+		#[pallet::weight(T::WeightInfo::create_tournament())]
+		pub fn create_tournament(
+			origin: OriginFor<T>,
+			entry_fee: BalanceOf<T, I>,
+			max_players: u32,
+			registration_deadline: T::BlockNumber,
+			rounds: u8,
+			prize_shares: Vec<Permill>,
+		) -> DispatchResult {
+			let organizer = ensure_signed(origin)?;
+
+			ensure!(
+				(2..=T::MaxTournamentPlayers::get()).contains(&max_players),
+				Error::<T, I>::InvalidPlayerCap
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				registration_deadline >= now
+					&& registration_deadline <= now.saturating_add(T::MaxRegistrationPeriod::get()),
+				Error::<T, I>::InvalidRegistrationDeadline
+			);
+			ensure!(ALLOWED_ROUNDS.contains(&rounds), Error::<T, I>::InvalidRoundCount);
+			ensure!(
+				!prize_shares.is_empty()
+					&& prize_shares.len() <= T::MaxPrizeTiers::get() as usize
+					&& prize_shares.iter().map(|share| share.deconstruct()).sum::<u32>()
+						== Permill::one().deconstruct(),
+				Error::<T, I>::InvalidPrizeDistribution
+			);
+			ensure!(
+				ActiveTournamentCount::<T, I>::get() < T::MaxActiveTournaments::get(),
+				Error::<T, I>::TooManyActiveTournaments
+			);
+
+			let deposit = T::TournamentDeposit::get();
+			T::Currency::reserve(&organizer, deposit)
+				.map_err(|_| Error::<T, I>::InsufficientFunds)?;
+
+			let tournament_id = NextTournamentId::<T, I>::get();
+			NextTournamentId::<T, I>::put(tournament_id.saturating_add(1));
+			ActiveTournamentCount::<T, I>::mutate(|count| *count = count.saturating_add(1));
+			Tournaments::<T, I>::insert(
+				tournament_id,
+				Tournament {
+					organizer: organizer.clone(),
+					deposit,
+					entry_fee,
+					max_players,
+					registration_deadline,
+					rounds,
+					prize_shares,
+					prize_pool: Zero::zero(),
+					state: TournamentState::Registration,
+				},
+			);
+			Self::schedule_tournament(
+				tournament_id,
+				registration_deadline.saturating_add(One::one()),
+			);
+
+			Self::deposit_event(Event::TournamentCreated {
+				tournament_id,
+				organizer,
+				entry_fee,
+				max_players,
+				registration_deadline,
+			});
+
+			Ok(())
+		}
+
+		/// Registers to a tournament, paying its entry fee into the prize pool.
+		#[pallet::weight(T::WeightInfo::join_tournament())]
+		pub fn join_tournament(
+			origin: OriginFor<T>,
+			tournament_id: TournamentId,
+		) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+
+			Tournaments::<T, I>::try_mutate(tournament_id, |tournament| {
+				let tournament = tournament.as_mut().ok_or(Error::<T, I>::TournamentNotFound)?;
+				ensure!(
+					tournament.state == TournamentState::Registration
+						&& frame_system::Pallet::<T>::block_number()
+							<= tournament.registration_deadline,
+					Error::<T, I>::RegistrationClosed
+				);
+
+				let mut players = TournamentPlayers::<T, I>::get(tournament_id);
+				ensure!(!players.contains(&player), Error::<T, I>::AlreadyRegistered);
+				ensure!(
+					(players.len() as u32) < tournament.max_players,
+					Error::<T, I>::TournamentFull
+				);
+				players.try_push(player.clone()).map_err(|_| Error::<T, I>::TournamentFull)?;
+
+				T::Currency::transfer(
+					&player,
+					&Self::account_id(),
+					tournament.entry_fee,
+					ExistenceRequirement::KeepAlive,
+				)
+				.map_err(|_| Error::<T, I>::InsufficientFunds)?;
+				tournament.prize_pool = tournament.prize_pool.saturating_add(tournament.entry_fee);
+				TournamentPlayers::<T, I>::insert(tournament_id, players);
+
+				Self::deposit_event(Event::TournamentJoined { tournament_id, player });
+
+				Ok(())
+			})
+		}
+
+		// play
 		// - Bet Id
 		// - Participant Id
-		// - Hand Payload (Encrypted<(HandType (Rock|Paper|Scissor))>, Public-Key)
-		#[pallet::weight(10_000)]
+		// - Hand commitment, computed off-chain with `PlayCommitment::hash` so the hand
+		//   itself is only disclosed in the reveal phase
+		#[pallet::weight(T::WeightInfo::play_challenge())]
 		pub fn play_challenge(
 			origin: OriginFor<T>,
 			challenge_id: ChallengeId,
-			challenge_play: ChallengePlay,
-			challenger_secret: u64,
+			play_hash: ChallengePlayHash,
 		) -> DispatchResult {
 			let player = ensure_signed(origin)?;
 
-			let challenge =
-				ChallengeStore::<T>::get(&challenge_id).ok_or(Error::<T>::ChallengeNotFound)?;
+			let challenge = ChallengeStore::<T, I>::get(&challenge_id)
+				.ok_or(Error::<T, I>::ChallengeNotFound)?;
 
-			if let ChallengeState::Accepted(ref challenge_state) = challenge {
+			if let ChallengeState::Accepted(mut challenge_state) = challenge {
 				ensure!(
 					challenge_state.contains_player(&player),
-					Error::<T>::CannotPlayInNonParticipatingChallenge
+					Error::<T, I>::CannotPlayInNonParticipatingChallenge
 				);
 
+				let round = challenge_state.round;
 				ensure!(
-					!ChallengePlaysStore::<T>::contains_key(&challenge_id, &player),
-					Error::<T>::CannotPlayInNonParticipatingChallenge
+					!ChallengePlaysStore::<T, I>::contains_key((challenge_id, round, &player)),
+					Error::<T, I>::ChallengeAlreadyPlayed
 				);
 
-				T::Currency::reserve(&player, challenge_state.bet_amount)?;
-
-				let play_hash = challenge_play.generate_hash(challenger_secret);
-				ChallengePlaysStore::<T>::insert(&challenge_id, &player, play_hash);
-				Self::deposit_event(Event::PlayedInChallenge(challenge_id, player));
-
-				if ChallengePlaysStore::<T>::iter_key_prefix(&challenge_id).count() == 2 {
-					Self::deposit_event(Event::ChallengeReadyForReveal(challenge_id));
+				ChallengePlaysStore::<T, I>::insert((challenge_id, round, &player), play_hash);
+				Self::deposit_event(Event::PlayedInChallenge {
+					challenge_id,
+					round,
+					player,
+					play_hash,
+				});
+
+				if ChallengePlaysStore::<T, I>::iter_key_prefix((challenge_id, round)).count() == 2
+				{
+					let now = frame_system::Pallet::<T>::block_number();
+					challenge_state.reveal_started_at = Some(now);
+					ChallengeStore::<T, I>::insert(
+						&challenge_id,
+						ChallengeState::Accepted(challenge_state),
+					);
+					Self::deposit_event(Event::ChallengeReadyForReveal {
+						challenge_id,
+						round,
+						reveal_deadline: now.saturating_add(T::RevealTimeout::get()),
+					});
 				}
 
 				Ok(())
 			} else {
-				Err(Error::<T>::ChallengeStateForbidsPlay.into())
+				Err(Error::<T, I>::ChallengeStateForbidsPlay.into())
+			}
+		}
+
+		// reveal
+		// - Challenge Id
+		// - Player Payload (HandType, Secret)
+		#[pallet::weight(
+			T::WeightInfo::reveal_play().saturating_add(Pallet::<T, I>::max_tournament_weight())
+		)]
+		pub fn reveal_play(
+			origin: OriginFor<T>,
+			challenge_id: ChallengeId,
+			hand: HandOf<T, I>,
+			salt: ChallengePlaySalt,
+		) -> DispatchResultWithPostInfo {
+			let player = ensure_signed(origin)?;
+
+			let challenge = ChallengeStore::<T, I>::get(&challenge_id)
+				.ok_or(Error::<T, I>::ChallengeNotFound)?;
+
+			if let ChallengeState::Accepted(challenge_state) = challenge {
+				ensure!(
+					challenge_state.contains_player(&player),
+					Error::<T, I>::CannotRevealNonParticipatingChallenge
+				);
+
+				let round = challenge_state.round;
+				ensure!(
+					!ChallengeRevealsStore::<T, I>::contains_key((challenge_id, round, &player)),
+					Error::<T, I>::ChallengeAlreadyRevealed
+				);
+
+				let rival_player =
+					challenge_state.get_rival(&player).ok_or(Error::<T, I>::InvalidState)?;
+
+				let player_hand_hash = Self::get_player_hand_hash(
+					challenge_id,
+					round,
+					&player,
+					Error::<T, I>::ChallengeNotReadyForReveal,
+				)?;
+				ensure!(
+					ChallengePlaysStore::<T, I>::contains_key((challenge_id, round, &rival_player)),
+					Error::<T, I>::ChallengeNotReadyForReveal
+				);
+
+				ensure!(
+					Self::verify_play(challenge_id, round, &player, &hand, salt, &player_hand_hash),
+					Error::<T, I>::InvalidHandHash
+				);
+				ensure!(T::GameRules::is_valid(&hand), Error::<T, I>::InvalidHand);
+
+				// The round is scored before anything is written, as paying out the bets can fail
+				let scored_round = match ChallengeRevealsStore::<T, I>::get((
+					challenge_id,
+					round,
+					&rival_player,
+				)) {
+					Some(rival_hand) => Some(Self::score_round(
+						challenge_state,
+						&player,
+						&hand,
+						&rival_player,
+						&rival_hand,
+					)?),
+					None => None,
+				};
+
+				ChallengeRevealsStore::<T, I>::insert((challenge_id, round, &player), hand.clone());
+				Self::deposit_event(Event::PlayRevealed {
+					challenge_id,
+					round,
+					player: player.clone(),
+					hand: hand.clone(),
+				});
+
+				let tournament_weight = scored_round
+					.map_or(0, |scored_round| Self::finish_round(challenge_id, scored_round));
+
+				Ok(Some(T::WeightInfo::reveal_play().saturating_add(tournament_weight)).into())
+			} else {
+				Err(Error::<T, I>::ChallengeStateForbidsResolution.into())
 			}
 		}
 
 		// reveal
 		// - Challenger Id
 		// - Challenger Payload (HandType, Secret)
-		#[pallet::weight(10_000)]
+		#[pallet::weight(
+			T::WeightInfo::reveal_challenge_results()
+				.saturating_add(Pallet::<T, I>::max_tournament_weight())
+		)]
 		pub fn reveal_challenge_results(
 			origin: OriginFor<T>,
-			origin_hand: ChallengePlay,
-			origin_secret: u64,
-			rival_hand: ChallengePlay,
-			rival_secret: u64,
+			origin_hand: HandOf<T, I>,
+			origin_secret: ChallengePlaySalt,
+			rival_hand: HandOf<T, I>,
+			rival_secret: ChallengePlaySalt,
 			challenge_id: ChallengeId,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let player = ensure_signed(origin)?;
 
-			Ok(ChallengeStore::<T>::try_mutate(&challenge_id, |challenge_entry| {
-				if let Some(challenge) = challenge_entry {
-					if let ChallengeState::Accepted(ref challenge_state) = challenge {
-						ensure!(
-							challenge_state.contains_player(&player),
-							Error::<T>::CannotPlayInNonParticipatingChallenge
-						);
+			let challenge = ChallengeStore::<T, I>::get(&challenge_id)
+				.ok_or(Error::<T, I>::ChallengeNotFound)?;
 
-						let player_hand_hash = Self::get_player_hand_hash(
-							&challenge_id,
-							&player,
-							Error::<T>::ChallengeStateForbidsResolution,
-						)?;
+			if let ChallengeState::Accepted(challenge_state) = challenge {
+				ensure!(
+					challenge_state.contains_player(&player),
+					Error::<T, I>::CannotPlayInNonParticipatingChallenge
+				);
 
-						ensure!(
-							origin_hand.compare_hash_with(origin_secret, player_hand_hash),
-							Error::<T>::InvalidHandHash
-						);
+				let round = challenge_state.round;
+				let player_hand_hash = Self::get_player_hand_hash(
+					challenge_id,
+					round,
+					&player,
+					Error::<T, I>::ChallengeStateForbidsResolution,
+				)?;
 
-						let rival_player =
-							challenge_state.get_rival(&player).ok_or(Error::<T>::InvalidState)?;
-						let rival_hand_hash = Self::get_player_hand_hash(
-							&challenge_id,
-							&rival_player,
-							Error::<T>::ChallengeStateForbidsResolution,
-						)?;
-						ensure!(
-							rival_hand.compare_hash_with(rival_secret, rival_hand_hash),
-							Error::<T>::InvalidHandHash
-						);
+				ensure!(
+					Self::verify_play(
+						challenge_id,
+						round,
+						&player,
+						&origin_hand,
+						origin_secret,
+						&player_hand_hash
+					),
+					Error::<T, I>::InvalidHandHash
+				);
 
-						let challenge_results = match origin_hand.beats(&rival_hand) {
-							PlayResult::Win => Some((&player, &rival_player)),
-							PlayResult::Lose => Some((&rival_player, &player)),
-							PlayResult::Draw => None,
-						};
+				let rival_player =
+					challenge_state.get_rival(&player).ok_or(Error::<T, I>::InvalidState)?;
+				let rival_hand_hash = Self::get_player_hand_hash(
+					challenge_id,
+					round,
+					&rival_player,
+					Error::<T, I>::ChallengeStateForbidsResolution,
+				)?;
+				ensure!(
+					Self::verify_play(
+						challenge_id,
+						round,
+						&rival_player,
+						&rival_hand,
+						rival_secret,
+						&rival_hand_hash
+					),
+					Error::<T, I>::InvalidHandHash
+				);
+				ensure!(
+					T::GameRules::is_valid(&origin_hand) && T::GameRules::is_valid(&rival_hand),
+					Error::<T, I>::InvalidHand
+				);
 
-						if let Some((winner, loser)) = challenge_results {
-							T::Currency::repatriate_reserved(
-								loser,
-								&winner,
-								challenge_state.bet_amount,
-								BalanceStatus::Reserved,
-							)?;
-							T::Currency::unreserve(
-								winner,
-								challenge_state.bet_amount.mul(2_u32.into()),
-							);
-
-							*challenge_entry =
-								Some(ChallengeState::Finished(FinishedChallenge::from_accepted(
-									challenge_state.clone(),
-									Some(winner.clone()),
-								)));
-
-							Self::deposit_event(Event::ChallengeFinished(Some(winner.clone())));
-
-							Ok(())
-						} else {
-							T::Currency::unreserve(&player, challenge_state.bet_amount);
-							T::Currency::unreserve(&rival_player, challenge_state.bet_amount);
-
-							*challenge_entry = Some(ChallengeState::Finished(
-								FinishedChallenge::from_accepted(challenge_state.clone(), None),
-							));
-
-							Self::deposit_event(Event::ChallengeFinished(None));
-
-							Ok(())
-						}
+				let scored_round = Self::score_round(
+					challenge_state,
+					&player,
+					&origin_hand,
+					&rival_player,
+					&rival_hand,
+				)?;
+				let tournament_weight = Self::finish_round(challenge_id, scored_round);
+
+				Ok(Some(
+					T::WeightInfo::reveal_challenge_results().saturating_add(tournament_weight),
+				)
+				.into())
+			} else {
+				Err(Error::<T, I>::ChallengeStateForbidsPlay.into())
+			}
+		}
+
+		/// Closes an accepted challenge whose current phase deadline has passed. Players that did
+		/// their part in the current round win the whole challenge against those that did not,
+		/// while the challenge ends with no winner if neither of them did.
+		#[pallet::weight(
+			T::WeightInfo::claim_timeout().saturating_add(Pallet::<T, I>::max_tournament_weight())
+		)]
+		pub fn claim_timeout(
+			origin: OriginFor<T>,
+			challenge_id: ChallengeId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let challenge = ChallengeStore::<T, I>::get(&challenge_id)
+				.ok_or(Error::<T, I>::ChallengeNotFound)?;
+
+			if let ChallengeState::Accepted(challenge_state) = challenge {
+				let now = frame_system::Pallet::<T>::block_number();
+				let challenger = challenge_state.challenger.clone();
+				let rival = challenge_state.rival.clone();
+				let round = challenge_state.round;
+
+				// Whether each player did its part in the current phase of the round
+				let (challenger_done, rival_done) = if let Some(reveal_started_at) =
+					challenge_state.reveal_started_at
+				{
+					ensure!(
+						now >= reveal_started_at.saturating_add(T::RevealTimeout::get()),
+						Error::<T, I>::ChallengeNotTimedOut
+					);
+
+					(
+						ChallengeRevealsStore::<T, I>::contains_key((
+							challenge_id,
+							round,
+							&challenger,
+						)),
+						ChallengeRevealsStore::<T, I>::contains_key((challenge_id, round, &rival)),
+					)
+				} else {
+					ensure!(
+						now >= challenge_state
+							.round_started_at
+							.saturating_add(T::PlayTimeout::get()),
+						Error::<T, I>::ChallengeNotTimedOut
+					);
+
+					(
+						ChallengePlaysStore::<T, I>::contains_key((
+							challenge_id,
+							round,
+							&challenger,
+						)),
+						ChallengePlaysStore::<T, I>::contains_key((challenge_id, round, &rival)),
+					)
+				};
+
+				let challenge_results = match (challenger_done, rival_done) {
+					(true, false) => Some((&challenger, &rival)),
+					(false, true) => Some((&rival, &challenger)),
+					_ => None,
+				};
+
+				let (payout, rake) = Self::pay_out(&challenge_state, challenge_results)?;
+
+				let forfeited = [(challenger_done, &challenger), (rival_done, &rival)]
+					.into_iter()
+					.filter(|(done, _)| !done)
+					.map(|(_, player)| player.clone())
+					.collect();
+				Self::deposit_event(Event::ChallengeTimedOut { challenge_id, forfeited });
+
+				let hands = (
+					ChallengeRevealsStore::<T, I>::get((challenge_id, round, &challenger)),
+					ChallengeRevealsStore::<T, I>::get((challenge_id, round, &rival)),
+				);
+				let tournament_weight = Self::finish_challenge(
+					challenge_id,
+					challenge_state,
+					hands,
+					challenge_results.map(|(winner, _)| winner.clone()),
+					payout,
+					rake,
+				);
+
+				Ok(Some(T::WeightInfo::claim_timeout().saturating_add(tournament_weight)).into())
+			} else {
+				Err(Error::<T, I>::ChallengeStateForbidsResolution.into())
+			}
+		}
+
+		/// Removes a challenge that finished at least `FinishedRetention` blocks ago, paying its
+		/// storage deposit to the caller.
+		#[pallet::weight(T::WeightInfo::prune_challenge())]
+		pub fn prune_challenge(origin: OriginFor<T>, challenge_id: ChallengeId) -> DispatchResult {
+			let pruner = ensure_signed(origin)?;
+
+			let challenge = ChallengeStore::<T, I>::get(&challenge_id)
+				.ok_or(Error::<T, I>::ChallengeNotFound)?;
+
+			if let ChallengeState::Finished(finished) = challenge {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(
+					now >= finished.finished_at.saturating_add(T::FinishedRetention::get()),
+					Error::<T, I>::ChallengeRetentionNotElapsed
+				);
+
+				let not_paid = T::Currency::repatriate_reserved(
+					&finished.challenger,
+					&pruner,
+					finished.deposit,
+					BalanceStatus::Free,
+				)
+				.map_err(|_| Error::<T, I>::InvalidState)?;
+
+				ChallengeStore::<T, I>::remove(&challenge_id);
+				PlayerChallenges::<T, I>::remove(&finished.challenger, challenge_id);
+				PlayerChallenges::<T, I>::remove(&finished.rival, challenge_id);
+
+				Self::deposit_event(Event::ChallengePruned {
+					challenge_id,
+					pruner,
+					reward: finished.deposit.saturating_sub(not_paid),
+				});
+
+				Ok(())
+			} else {
+				Err(Error::<T, I>::CannotPruneUnfinishedChallenge.into())
+			}
+		}
+	}
+
+	// Internal functions of the pallet
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Checks that a revealed hand and salt match the commitment sent by the player.
+		pub fn verify_play(
+			challenge_id: ChallengeId,
+			round: ChallengeRound,
+			player: &T::AccountId,
+			hand: &HandOf<T, I>,
+			salt: ChallengePlaySalt,
+			play_hash: &ChallengePlayHash,
+		) -> bool {
+			PlayCommitment::new(
+				salt,
+				hand.clone(),
+				challenge_id,
+				round,
+				player.clone(),
+				frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+			)
+			.verify(play_hash)
+		}
+
+		/// Checks that a revealed hand and salt match the commitment sent by the player in a game
+		/// against the house.
+		pub fn verify_house_play(
+			nonce: HouseNonce,
+			player: &T::AccountId,
+			hand: &HandOf<T, I>,
+			salt: ChallengePlaySalt,
+			play_hash: &ChallengePlayHash,
+		) -> bool {
+			PlayCommitment::house(
+				salt,
+				hand.clone(),
+				player.clone(),
+				nonce,
+				frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+			)
+			.verify(play_hash)
+		}
+
+		fn do_create_challenge(
+			challenger: T::AccountId,
+			intended_rival: Option<T::AccountId>,
+			rating_band: Option<RatingBand>,
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+			rounds: u8,
+		) -> Result<(), Error<T, I>> {
+			Self::ensure_valid_bet(&asset, bet_amount)?;
+			ensure!(ALLOWED_ROUNDS.contains(&rounds), Error::<T, I>::InvalidRoundCount);
+
+			Self::ensure_can_join(&challenger)?;
+
+			let deposit = T::ChallengeDeposit::get();
+			Self::escrow_bet(&challenger, asset, bet_amount, deposit)?;
+
+			let challenge_id = NextBetId::<T, I>::get();
+			let challenge_state = ChallengeState::Open(OpenChallenge {
+				challenger: challenger.clone(),
+				asset,
+				bet_amount,
+				deposit,
+				intended_rival: intended_rival.clone(),
+				rating_band,
+				rounds,
+				created_at: frame_system::Pallet::<T>::block_number(),
+			});
+
+			NextBetId::<T, I>::mutate(|x| *x += 1);
+
+			ChallengeStore::<T, I>::insert(&challenge_id, challenge_state);
+			Self::track_challenge(&challenger, challenge_id);
+			if intended_rival.is_none() {
+				Self::add_to_lobby(asset, bet_amount, challenge_id, &challenger, rating_band);
+			}
+
+			Self::deposit_event(Event::ChallengeCreated {
+				challenge_id,
+				challenger,
+				intended_rival,
+				asset,
+				bet_amount,
+				rounds,
+			});
+
+			Ok(())
+		}
+
+		fn do_enter_challenge(
+			rival: T::AccountId,
+			challenge_id: ChallengeId,
+		) -> Result<(), Error<T, I>> {
+			ChallengeStore::<T, I>::try_mutate(&challenge_id, |challenge_entry| {
+				ensure!(challenge_entry.is_some(), Error::<T, I>::ChallengeNotFound);
+
+				let challenge_state = challenge_entry.as_mut().unwrap();
+
+				if let ChallengeState::Open(open_state) = challenge_state {
+					if open_state.challenger == rival {
+						Err(Error::<T, I>::CannotChallengeOneself)
+					} else if open_state
+						.intended_rival
+						.as_ref()
+						.map_or(false, |intended| *intended != rival)
+					{
+						Err(Error::<T, I>::NotIntendedRival)
+					} else if open_state
+						.rating_band
+						.map_or(false, |band| !band.contains(Ratings::<T, I>::get(&rival)))
+					{
+						Err(Error::<T, I>::RatingOutOfBand)
 					} else {
-						Err(Error::<T>::ChallengeStateForbidsPlay)
+						Self::ensure_can_join(&rival)?;
+						Self::escrow_bet(
+							&rival,
+							open_state.asset,
+							open_state.bet_amount,
+							Zero::zero(),
+						)?;
+						Self::track_challenge(&rival, challenge_id);
+						Self::remove_from_lobby(
+							open_state.asset,
+							open_state.bet_amount,
+							challenge_id,
+						);
+
+						Self::deposit_event(Event::EnteredChallenge {
+							challenge_id,
+							challenger: open_state.challenger.clone(),
+							rival: rival.clone(),
+							bet_amount: open_state.bet_amount,
+						});
+						*challenge_state = ChallengeState::Accepted(AcceptedChallenge::from_open(
+							open_state.clone(),
+							rival,
+							frame_system::Pallet::<T>::block_number(),
+						));
+						Ok(())
 					}
 				} else {
-					Err(Error::<T>::ChallengeNotFound)
+					Err(Error::<T, I>::ChallengeNotOpen)
 				}
-			})?)
+			})
+		}
+
+		/// Lists an open challenge in the lobby, keeping it sorted. The challenge is not listed if
+		/// the lobby is full.
+		fn add_to_lobby(
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+			challenge_id: ChallengeId,
+			challenger: &T::AccountId,
+			rating_band: Option<RatingBand>,
+		) {
+			Lobby::<T, I>::mutate(|lobby| {
+				let index = lobby.partition_point(|(bet_asset, bet, id, _, _)| {
+					(*bet_asset, *bet, *id) < (asset, bet_amount, challenge_id)
+				});
+				let _ = lobby.try_insert(
+					index,
+					(asset, bet_amount, challenge_id, challenger.clone(), rating_band),
+				);
+			});
+		}
+
+		fn remove_from_lobby(
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+			challenge_id: ChallengeId,
+		) {
+			Lobby::<T, I>::mutate(|lobby| {
+				if let Ok(index) = lobby.binary_search_by(|(bet_asset, bet, id, _, _)| {
+					(*bet_asset, *bet, *id).cmp(&(asset, bet_amount, challenge_id))
+				}) {
+					lobby.remove(index);
+				}
+			});
+		}
+
+		/// Account holding the bets placed in assets until they are settled.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Account holding the bankroll of the house, which anyone can fund.
+		pub fn house_account_id() -> T::AccountId {
+			T::PalletId::get().into_sub_account(b"house")
+		}
+
+		/// Checks that bets can be placed in the asset and that the amount reaches its minimum.
+		fn ensure_valid_bet(
+			asset: &BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+		) -> Result<(), Error<T, I>> {
+			let min_amount = match asset {
+				None => T::MinBetAmount::get(),
+				Some(asset_id) => {
+					AssetMinBet::<T, I>::get(asset_id).ok_or(Error::<T, I>::AssetNotAccepted)?
+				},
+			};
+			ensure!(bet_amount >= min_amount, Error::<T, I>::InsufficientBetAmount);
+			Ok(())
+		}
+
+		/// Places a bet in escrow along with a storage deposit in the native currency. Bets in
+		/// the native currency are reserved, while bets in assets are moved to the pallet account.
+		fn escrow_bet(
+			who: &T::AccountId,
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+			deposit: BalanceOf<T, I>,
+		) -> Result<(), Error<T, I>> {
+			match asset {
+				None => T::Currency::reserve(who, bet_amount.saturating_add(deposit)),
+				Some(asset_id) => {
+					ensure!(
+						T::Currency::can_reserve(who, deposit),
+						Error::<T, I>::InsufficientFunds
+					);
+					<T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+						asset_id,
+						who,
+						&Self::account_id(),
+						bet_amount,
+						true,
+					)
+					.and_then(|_| T::Currency::reserve(who, deposit))
+				},
+			}
+			.map_err(|_| Error::<T, I>::InsufficientFunds)
+		}
+
+		/// Gives back a bet held in escrow along with a storage deposit.
+		fn release_bet(
+			who: &T::AccountId,
+			asset: BetAssetOf<T, I>,
+			bet_amount: BalanceOf<T, I>,
+			deposit: BalanceOf<T, I>,
+		) {
+			match asset {
+				None => {
+					T::Currency::unreserve(who, bet_amount.saturating_add(deposit));
+				},
+				Some(asset_id) => {
+					let _ = Self::transfer_from_escrow(asset_id, who, bet_amount);
+					T::Currency::unreserve(who, deposit);
+				},
+			}
+		}
+
+		fn transfer_from_escrow(
+			asset_id: T::AssetId,
+			dest: &T::AccountId,
+			amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			<T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+				asset_id,
+				&Self::account_id(),
+				dest,
+				amount,
+				false,
+			)
+			.map(|_| ())
+		}
+
+		fn ensure_can_join(player: &T::AccountId) -> Result<(), Error<T, I>> {
+			ensure!(
+				ActiveChallengeCount::<T, I>::get(player) < T::MaxActiveChallengesPerAccount::get(),
+				Error::<T, I>::TooManyActiveChallenges
+			);
+			Ok(())
+		}
+
+		/// Records a challenge the player has just created or entered as active.
+		fn track_challenge(player: &T::AccountId, challenge_id: ChallengeId) {
+			PlayerChallenges::<T, I>::insert(player, challenge_id, ());
+			ActiveChallengeCount::<T, I>::mutate(player, |count| *count = count.saturating_add(1));
+		}
+
+		/// Stops counting a challenge as active for the player once it is cancelled or finished.
+		fn release_challenge(player: &T::AccountId) {
+			ActiveChallengeCount::<T, I>::mutate_exists(player, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0)
+			});
 		}
-	}
 
-	// Internal functions of the pallet
-	impl<T: Config> Pallet<T> {
 		fn get_player_hand_hash(
-			challenge_id: &ChallengeId,
+			challenge_id: ChallengeId,
+			round: ChallengeRound,
 			player_id: &T::AccountId,
-			on_error: Error<T>,
-		) -> Result<ChallengePlayHash, Error<T>> {
-			if let Some(player_hand) = ChallengePlaysStore::<T>::get(&challenge_id, &player_id) {
+			on_error: Error<T, I>,
+		) -> Result<ChallengePlayHash, Error<T, I>> {
+			if let Some(player_hand) =
+				ChallengePlaysStore::<T, I>::get((challenge_id, round, player_id))
+			{
 				Ok(player_hand)
 			} else {
 				Err(on_error)
 			}
 		}
+
+		/// Scores the current round of an accepted challenge given the verified hands of both
+		/// players, and pays out the reserved bets if a player has won enough rounds, or after a
+		/// draw in a single round challenge. Paying out is the only step that can fail, so nothing
+		/// else is written until the round is finished with `finish_round`.
+		fn score_round(
+			mut challenge_state: AcceptedChallengeOf<T, I>,
+			player: &T::AccountId,
+			player_hand: &HandOf<T, I>,
+			rival_player: &T::AccountId,
+			rival_hand: &HandOf<T, I>,
+		) -> Result<ScoredRound<T, I>, Error<T, I>> {
+			let round_winner = match T::GameRules::outcome(player_hand, rival_hand) {
+				PlayResult::Win => Some(player.clone()),
+				PlayResult::Lose => Some(rival_player.clone()),
+				PlayResult::Draw => None,
+			};
+
+			let hands = if *player == challenge_state.challenger {
+				(player_hand.clone(), rival_hand.clone())
+			} else {
+				(rival_hand.clone(), player_hand.clone())
+			};
+
+			match &round_winner {
+				Some(winner) if *winner == challenge_state.challenger => {
+					challenge_state.score.0 = challenge_state.score.0.saturating_add(1)
+				},
+				Some(_) => challenge_state.score.1 = challenge_state.score.1.saturating_add(1),
+				None => {},
+			}
+
+			let (challenger, rival) =
+				(challenge_state.challenger.clone(), challenge_state.rival.clone());
+			let wins_needed = challenge_state.wins_needed();
+			let challenge_results = if challenge_state.score.0 >= wins_needed {
+				Some((&challenger, &rival))
+			} else if challenge_state.score.1 >= wins_needed {
+				Some((&rival, &challenger))
+			} else if challenge_state.rounds > 1 {
+				// Nobody won enough rounds yet, drawn rounds being replayed
+				return Ok(ScoredRound { challenge_state, hands, round_winner, settlement: None });
+			} else {
+				None
+			};
+
+			let (payout, rake) = Self::pay_out(&challenge_state, challenge_results)?;
+			let winner = challenge_results.map(|(winner, _)| winner.clone());
+
+			Ok(ScoredRound {
+				challenge_state,
+				hands,
+				round_winner,
+				settlement: Some((winner, payout, rake)),
+			})
+		}
+
+		/// Records a round scored by `score_round`, finishing the challenge if the round settled
+		/// it and otherwise moving on to the next round. Returns the weight consumed by advancing
+		/// the tournament the challenge is a match of.
+		fn finish_round(challenge_id: ChallengeId, scored_round: ScoredRound<T, I>) -> Weight {
+			let ScoredRound {
+				mut challenge_state,
+				hands: (challenger_hand, rival_hand),
+				round_winner,
+				settlement,
+			} = scored_round;
+
+			Self::deposit_event(Event::RoundFinished {
+				challenge_id,
+				round: challenge_state.round,
+				challenger_hand: challenger_hand.clone(),
+				rival_hand: rival_hand.clone(),
+				winner: round_winner,
+				score: challenge_state.score,
+			});
+
+			PlayerStats::<T, I>::mutate(&challenge_state.challenger, |stats| {
+				stats.record_hand(challenger_hand.clone())
+			});
+			PlayerStats::<T, I>::mutate(&challenge_state.rival, |stats| {
+				stats.record_hand(rival_hand.clone())
+			});
+
+			match settlement {
+				Some((winner, payout, rake)) => Self::finish_challenge(
+					challenge_id,
+					challenge_state,
+					(Some(challenger_hand), Some(rival_hand)),
+					winner,
+					payout,
+					rake,
+				),
+				None => {
+					let finished_round = challenge_state.round;
+					challenge_state.round = finished_round.saturating_add(1);
+					challenge_state.round_started_at = frame_system::Pallet::<T>::block_number();
+					challenge_state.reveal_started_at = None;
+					ChallengeStore::<T, I>::insert(
+						&challenge_id,
+						ChallengeState::Accepted(challenge_state),
+					);
+
+					let _ = ChallengePlaysStore::<T, I>::remove_prefix(
+						(challenge_id, finished_round),
+						None,
+					);
+					let _ = ChallengeRevealsStore::<T, I>::remove_prefix(
+						(challenge_id, finished_round),
+						None,
+					);
+
+					0
+				},
+			}
+		}
+
+		/// Moves the bet of the loser to the winner, minus the rake which is shared between the
+		/// season pool and `FeeDestination`, or handed to `AssetFeeCollector` for bets in assets,
+		/// and releases both bets, or simply releases both bets if there is no winner. Returns the
+		/// amount paid to the winner and the rake.
+		fn pay_out(
+			challenge_state: &AcceptedChallengeOf<T, I>,
+			challenge_results: Option<(&T::AccountId, &T::AccountId)>,
+		) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), Error<T, I>> {
+			let bet_amount = challenge_state.bet_amount;
+			match (challenge_results, challenge_state.asset) {
+				(Some((winner, loser)), None) => {
+					let rake = T::RakePercent::get() * bet_amount;
+					let payout = bet_amount.saturating_sub(rake);
+
+					T::Currency::repatriate_reserved(
+						loser,
+						winner,
+						payout,
+						BalanceStatus::Reserved,
+					)
+					.map_err(|_| Error::<T, I>::InvalidState)?;
+					let (fee, _) = T::Currency::slash_reserved(loser, rake);
+					let (season_fee, fee) = fee.split(T::SeasonRakeShare::get() * rake);
+					let season_amount = season_fee.peek();
+					// The whole rake goes to `FeeDestination` until the pallet account exists
+					let fee =
+						match T::Currency::resolve_into_existing(&Self::account_id(), season_fee) {
+							Ok(()) => {
+								SeasonPool::<T, I>::mutate(|pool| {
+									*pool = pool.saturating_add(season_amount)
+								});
+								fee
+							},
+							Err(season_fee) => fee.merge(season_fee),
+						};
+					T::FeeDestination::on_unbalanced(fee);
+					T::Currency::unreserve(winner, bet_amount.saturating_add(payout));
+
+					Ok((payout, rake))
+				},
+				(Some((winner, _)), Some(asset_id)) => {
+					let fee_collector = T::AssetFeeCollector::get();
+					let rake = T::RakePercent::get() * bet_amount;
+					// The winner keeps the rake if the fee collector cannot receive it
+					let rake = <T::Assets as fungibles::Inspect<T::AccountId>>::can_deposit(
+						asset_id,
+						&fee_collector,
+						rake,
+					)
+					.into_result()
+					.map_or(Zero::zero(), |_| rake);
+					let payout = bet_amount.saturating_sub(rake);
+
+					// The winner is paid first so that nothing is moved if paying them fails
+					Self::transfer_from_escrow(asset_id, winner, bet_amount.saturating_add(payout))
+						.map_err(|_| Error::<T, I>::InvalidState)?;
+					if !rake.is_zero() {
+						let _ = Self::transfer_from_escrow(asset_id, &fee_collector, rake);
+					}
+
+					Ok((payout, rake))
+				},
+				(None, asset) => {
+					Self::release_bet(&challenge_state.challenger, asset, bet_amount, Zero::zero());
+					Self::release_bet(&challenge_state.rival, asset, bet_amount, Zero::zero());
+
+					Ok((Zero::zero(), Zero::zero()))
+				},
+			}
+		}
+
+		/// Records the outcome of a challenge whose bets have been paid out. A challenge in which
+		/// neither player revealed a hand and nobody won was not played, so it is left out of the
+		/// statistics and ratings of the players. Returns the weight consumed by advancing the
+		/// tournament the challenge is a match of.
+		fn finish_challenge(
+			challenge_id: ChallengeId,
+			challenge_state: AcceptedChallengeOf<T, I>,
+			(challenger_hand, rival_hand): (Option<HandOf<T, I>>, Option<HandOf<T, I>>),
+			winner: Option<T::AccountId>,
+			payout: BalanceOf<T, I>,
+			rake: BalanceOf<T, I>,
+		) -> Weight {
+			let (challenger, rival) =
+				(challenge_state.challenger.clone(), challenge_state.rival.clone());
+			let played = winner.is_some() || challenger_hand.is_some() || rival_hand.is_some();
+			if played {
+				Self::update_stats(&challenge_state, winner.as_ref(), payout);
+			}
+
+			ChallengeStore::<T, I>::insert(
+				&challenge_id,
+				ChallengeState::Finished(FinishedChallenge::from_accepted(
+					challenge_state,
+					(challenger_hand.clone(), rival_hand.clone()),
+					winner.clone(),
+					frame_system::Pallet::<T>::block_number(),
+				)),
+			);
+
+			// The hands are kept in the finished challenge, so the plays are no longer needed
+			let _ = ChallengePlaysStore::<T, I>::remove_prefix((challenge_id,), None);
+			let _ = ChallengeRevealsStore::<T, I>::remove_prefix((challenge_id,), None);
+
+			Self::release_challenge(&challenger);
+			Self::release_challenge(&rival);
+			if played {
+				Self::update_ratings(&challenger, &rival, winner.as_ref());
+			}
+			let drawn = winner.is_none() && challenger_hand.is_some() && rival_hand.is_some();
+			Self::award_season_points(&challenger, &rival, winner.as_ref(), drawn);
+
+			Self::deposit_event(Event::ChallengeFinished {
+				challenge_id,
+				challenger: challenger.clone(),
+				rival: rival.clone(),
+				challenger_hand,
+				rival_hand,
+				winner: winner.clone(),
+				payout,
+				rake,
+			});
+
+			match TournamentMatches::<T, I>::take(challenge_id) {
+				Some((tournament_id, slot)) => Self::advance_tournament(
+					tournament_id,
+					slot,
+					&challenger,
+					&rival,
+					winner.as_ref(),
+					drawn,
+				),
+				None => 0,
+			}
+		}
+
+		/// Weight of advancing a tournament from one of its matches in the worst case, which is
+		/// the final match of a full tournament paying out its prizes.
+		fn max_tournament_weight() -> Weight {
+			T::WeightInfo::finish_tournament(T::MaxTournamentPlayers::get())
+				.max(T::WeightInfo::seed_tournament_round(2))
+		}
+
+		/// Schedules the next step of a tournament, which is always the only one scheduled for
+		/// it, so the block cannot be full.
+		fn schedule_tournament(tournament_id: TournamentId, at: T::BlockNumber) {
+			TournamentSchedule::<T, I>::mutate(at, |scheduled| {
+				let _ = scheduled.try_push(tournament_id);
+			});
+		}
+
+		/// Creates the matches of the next round of a tournament, or of its first round once its
+		/// registration has closed. Returns the weight consumed.
+		fn seed_round(tournament_id: TournamentId) -> Weight {
+			let mut tournament = match Tournaments::<T, I>::get(tournament_id) {
+				Some(tournament) => tournament,
+				None => return T::DbWeight::get().reads(1),
+			};
+
+			let (round, players, pairings) = match tournament.state {
+				TournamentState::Registration => {
+					let players = TournamentPlayers::<T, I>::get(tournament_id).into_inner();
+					if players.len() < 2 {
+						Self::cancel_tournament(tournament_id, tournament, &players);
+						return T::WeightInfo::seed_tournament_round(players.len() as u32);
+					}
+
+					// Best rated players first, earliest registered first among equal ratings
+					let mut seeds: Vec<_> = players
+						.into_iter()
+						.map(|player| (Ratings::<T, I>::get(&player), player))
+						.enumerate()
+						.collect();
+					seeds.sort_by(|(index_a, (rating_a, _)), (index_b, (rating_b, _))| {
+						rating_b.cmp(rating_a).then_with(|| index_a.cmp(index_b))
+					});
+					let seeds: Vec<_> = seeds.into_iter().map(|(_, (_, player))| player).collect();
+					(0, seeds.len() as u32, tournament::first_round_pairings(&seeds))
+				},
+				TournamentState::Running(round) => {
+					let roster: Vec<_> = TournamentBracket::<T, I>::get(tournament_id)
+						.into_inner()
+						.into_iter()
+						.flatten()
+						.collect();
+					(round.saturating_add(1), roster.len() as u32, tournament::pairings(&roster))
+				},
+				_ => return T::DbWeight::get().reads(1),
+			};
+
+			let mut bracket = BoundedVec::default();
+			for (slot, (challenger, rival)) in pairings.into_iter().enumerate() {
+				match rival {
+					Some(rival) => {
+						let _ = bracket.try_push(None);
+						Self::create_tournament_match(
+							tournament_id,
+							round,
+							slot as u32,
+							&tournament,
+							challenger,
+							rival,
+						);
+					},
+					// Players without a rival advance right away
+					None => {
+						let _ = bracket.try_push(Some(challenger));
+					},
+				}
+			}
+			TournamentBracket::<T, I>::insert(tournament_id, bracket);
+			tournament.state = TournamentState::Running(round);
+			Tournaments::<T, I>::insert(tournament_id, tournament);
+
+			Self::deposit_event(Event::TournamentRoundStarted { tournament_id, round, players });
+
+			T::WeightInfo::seed_tournament_round(players)
+		}
+
+		fn create_tournament_match(
+			tournament_id: TournamentId,
+			round: TournamentRound,
+			slot: u32,
+			tournament: &TournamentOf<T, I>,
+			challenger: T::AccountId,
+			rival: T::AccountId,
+		) {
+			let challenge_id = NextBetId::<T, I>::get();
+			NextBetId::<T, I>::mutate(|x| *x += 1);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let open_challenge = OpenChallenge {
+				challenger: challenger.clone(),
+				asset: None,
+				bet_amount: Zero::zero(),
+				deposit: Zero::zero(),
+				intended_rival: Some(rival.clone()),
+				rating_band: None,
+				rounds: tournament.rounds,
+				created_at: now,
+			};
+			ChallengeStore::<T, I>::insert(
+				&challenge_id,
+				ChallengeState::Accepted(AcceptedChallenge::from_open(
+					open_challenge,
+					rival.clone(),
+					now,
+				)),
+			);
+			Self::track_challenge(&challenger, challenge_id);
+			Self::track_challenge(&rival, challenge_id);
+			TournamentMatches::<T, I>::insert(challenge_id, (tournament_id, slot));
+
+			Self::deposit_event(Event::TournamentMatchCreated {
+				tournament_id,
+				round,
+				challenge_id,
+				challenger,
+				rival,
+			});
+		}
+
+		/// Moves the winner of a tournament match to the next round, scheduling the round once
+		/// all matches of the current one are played. Drawn matches are replayed, while the first
+		/// player of the match advances if neither player played in it. Returns the weight
+		/// consumed.
+		fn advance_tournament(
+			tournament_id: TournamentId,
+			slot: u32,
+			challenger: &T::AccountId,
+			rival: &T::AccountId,
+			winner: Option<&T::AccountId>,
+			drawn: bool,
+		) -> Weight {
+			let tournament = match Tournaments::<T, I>::get(tournament_id) {
+				Some(tournament) => tournament,
+				None => return T::DbWeight::get().reads(1),
+			};
+			let round = match tournament.state {
+				TournamentState::Running(round) => round,
+				_ => return T::DbWeight::get().reads(1),
+			};
+
+			// Replaying a match or scheduling the next round costs no more than seeding a round
+			// of a single match
+			if drawn {
+				Self::create_tournament_match(
+					tournament_id,
+					round,
+					slot,
+					&tournament,
+					challenger.clone(),
+					rival.clone(),
+				);
+				return T::WeightInfo::seed_tournament_round(2);
+			}
+
+			let (winner, loser) = match winner {
+				Some(winner) if winner == rival => (rival, challenger),
+				_ => (challenger, rival),
+			};
+			TournamentEliminations::<T, I>::mutate(tournament_id, round, |eliminated| {
+				let _ = eliminated.try_push(loser.clone());
+			});
+
+			let bracket = TournamentBracket::<T, I>::mutate(tournament_id, |bracket| {
+				if let Some(entry) = bracket.get_mut(slot as usize) {
+					*entry = Some(winner.clone());
+				}
+				bracket.clone()
+			});
+			if bracket.iter().all(Option::is_some) {
+				if bracket.len() == 1 {
+					let players =
+						TournamentPlayers::<T, I>::decode_len(tournament_id).unwrap_or_default();
+					Self::finish_tournament(tournament_id, tournament, round, winner.clone());
+					return T::WeightInfo::finish_tournament(players as u32);
+				}
+				Self::schedule_tournament(
+					tournament_id,
+					frame_system::Pallet::<T>::block_number().saturating_add(One::one()),
+				);
+			}
+
+			T::WeightInfo::seed_tournament_round(2)
+		}
+
+		/// Pays out the prize pool of a tournament to its best places. Shares of places nobody
+		/// reached and rounding leftovers go to the champion.
+		fn finish_tournament(
+			tournament_id: TournamentId,
+			mut tournament: TournamentOf<T, I>,
+			final_round: TournamentRound,
+			champion: T::AccountId,
+		) {
+			let pool = tournament.prize_pool;
+			let mut prizes: Vec<(T::AccountId, BalanceOf<T, I>)> = Vec::new();
+			let mut paid = BalanceOf::<T, I>::zero();
+			for (place, share) in tournament.prize_shares.iter().enumerate().skip(1) {
+				let recipients = tournament::elimination_round_of_place(place, final_round)
+					.map(|round| {
+						TournamentEliminations::<T, I>::get(tournament_id, round).into_inner()
+					})
+					.unwrap_or_default();
+				if recipients.is_empty() {
+					continue;
+				}
+
+				let prize = share.mul_floor(pool) / (recipients.len() as u32).into();
+				for recipient in recipients {
+					paid = paid.saturating_add(prize);
+					prizes.push((recipient, prize));
+				}
+			}
+			prizes.insert(0, (champion.clone(), pool.saturating_sub(paid)));
+
+			let account_id = Self::account_id();
+			for (recipient, prize) in prizes.iter() {
+				let _ = T::Currency::transfer(
+					&account_id,
+					recipient,
+					*prize,
+					ExistenceRequirement::AllowDeath,
+				);
+			}
+
+			tournament.state = TournamentState::Finished { champion: champion.clone() };
+			Self::end_tournament(tournament_id, tournament);
+
+			Self::deposit_event(Event::TournamentFinished { tournament_id, champion, prizes });
+		}
+
+		/// Refunds the entry fees of a tournament that did not gather enough players.
+		fn cancel_tournament(
+			tournament_id: TournamentId,
+			mut tournament: TournamentOf<T, I>,
+			players: &[T::AccountId],
+		) {
+			let account_id = Self::account_id();
+			for player in players {
+				let _ = T::Currency::transfer(
+					&account_id,
+					player,
+					tournament.entry_fee,
+					ExistenceRequirement::AllowDeath,
+				);
+			}
+
+			tournament.state = TournamentState::Cancelled;
+			Self::end_tournament(tournament_id, tournament);
+
+			Self::deposit_event(Event::TournamentCancelled { tournament_id });
+		}
+
+		/// Keeps the final state of a tournament, dropping its bracket, and returns the deposit of
+		/// its organizer.
+		fn end_tournament(tournament_id: TournamentId, tournament: TournamentOf<T, I>) {
+			T::Currency::unreserve(&tournament.organizer, tournament.deposit);
+			Tournaments::<T, I>::insert(tournament_id, tournament);
+			TournamentPlayers::<T, I>::remove(tournament_id);
+			TournamentBracket::<T, I>::remove(tournament_id);
+			let _ = TournamentEliminations::<T, I>::remove_prefix(tournament_id, None);
+			ActiveTournamentCount::<T, I>::mutate(|count| *count = count.saturating_sub(1));
+		}
+
+		/// Records the outcome of a finished challenge in the statistics of its players. Only bets
+		/// in the native currency are added to their amounts.
+		fn update_stats(
+			challenge_state: &AcceptedChallengeOf<T, I>,
+			winner: Option<&T::AccountId>,
+			payout: BalanceOf<T, I>,
+		) {
+			let (bet_amount, payout) = match challenge_state.asset {
+				None => (challenge_state.bet_amount, payout),
+				Some(_) => (Zero::zero(), Zero::zero()),
+			};
+			let challenger_result = match winner {
+				Some(winner) if *winner == challenge_state.challenger => PlayResult::Win,
+				Some(_) => PlayResult::Lose,
+				None => PlayResult::Draw,
+			};
+
+			let rival_result = challenger_result.reverse();
+
+			for (player, result) in [
+				(&challenge_state.challenger, challenger_result),
+				(&challenge_state.rival, rival_result),
+			] {
+				let amount = if result == PlayResult::Win { payout } else { bet_amount };
+				PlayerStats::<T, I>::mutate(player, |stats| {
+					stats.record_result(result, bet_amount, amount)
+				});
+			}
+		}
+
+		/// Draws the hand of the house in a pending house game, from randomness that was not known
+		/// when the player committed to its hand. Hooks may run before the randomness source is
+		/// updated, so the hash of the block that included the commitment, which the player could
+		/// not know, is part of the subject.
+		fn draw_house_hand(player: &T::AccountId, now: T::BlockNumber) {
+			let parent_hash = frame_system::Pallet::<T>::parent_hash();
+			HouseGames::<T, I>::mutate(player, |game| {
+				let game = match game {
+					Some(game) => game,
+					None => return,
+				};
+
+				let subject = (b"rps/house", game.game_id, &game.commitment, &parent_hash).encode();
+				let (seed, _) = T::Randomness::random(&subject);
+				let index = u32::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+					.unwrap_or_default() as usize;
+				let hands = T::GameRules::hands();
+				// The game is refunded on timeout if the rules have no valid hand
+				if let Some(house_hand) = hands.get(index % hands.len().max(1)) {
+					game.house_hand = Some((house_hand.clone(), now));
+
+					Self::deposit_event(Event::HouseHandDrawn {
+						game_id: game.game_id,
+						player: player.clone(),
+						house_hand: house_hand.clone(),
+					});
+				}
+			});
+		}
+
+		/// Moves the bet of a house game from the loser to the winner, or gives it back on a draw.
+		fn settle_house_game(
+			player: T::AccountId,
+			game: HouseGameOf<T, I>,
+			hand: Option<HandOf<T, I>>,
+			house_hand: Option<HandOf<T, I>>,
+			result: PlayResult,
+		) -> DispatchResult {
+			let bet_amount = game.bet_amount;
+			match result {
+				PlayResult::Win => {
+					T::Currency::transfer(
+						&Self::house_account_id(),
+						&player,
+						bet_amount,
+						ExistenceRequirement::AllowDeath,
+					)
+					.map_err(|_| Error::<T, I>::InvalidState)?;
+					T::Currency::unreserve(&player, bet_amount);
+				},
+				PlayResult::Lose => {
+					T::Currency::repatriate_reserved(
+						&player,
+						&Self::house_account_id(),
+						bet_amount,
+						BalanceStatus::Free,
+					)
+					.map_err(|_| Error::<T, I>::InvalidState)?;
+				},
+				PlayResult::Draw => {
+					T::Currency::unreserve(&player, bet_amount);
+				},
+			}
+
+			HouseGames::<T, I>::remove(&player);
+			HouseExposure::<T, I>::mutate(|exposure| {
+				*exposure = exposure.saturating_sub(bet_amount)
+			});
+
+			Self::deposit_event(Event::HouseGameFinished {
+				game_id: game.game_id,
+				player,
+				hand,
+				house_hand,
+				result,
+			});
+
+			Ok(())
+		}
+
+		/// Adds the points won in a finished challenge to the season points of its players,
+		/// moving them up the leaderboard. Challenges timed out without a winner are worth no
+		/// points.
+		fn award_season_points(
+			challenger: &T::AccountId,
+			rival: &T::AccountId,
+			winner: Option<&T::AccountId>,
+			drawn: bool,
+		) {
+			let awards = match winner {
+				Some(winner) => [(winner, season::POINTS_PER_WIN)].to_vec(),
+				None if drawn => {
+					[(challenger, season::POINTS_PER_DRAW), (rival, season::POINTS_PER_DRAW)]
+						.to_vec()
+				},
+				None => return,
+			};
+
+			let current_season = CurrentSeason::<T, I>::get();
+			for (player, award) in awards {
+				let points =
+					PlayerSeasonPoints::<T, I>::mutate(player, |(points_season, points)| {
+						if *points_season != current_season {
+							*points_season = current_season;
+							*points = 0;
+						}
+						*points = points.saturating_add(award);
+						*points
+					});
+
+				Leaderboard::<T, I>::mutate(|leaderboard| {
+					let mut entries = sp_std::mem::take(leaderboard).into_inner();
+					season::rank(
+						&mut entries,
+						player.clone(),
+						points,
+						T::LeaderboardSize::get() as usize,
+					);
+					*leaderboard = BoundedVec::try_from(entries).unwrap_or_default();
+				});
+			}
+		}
+
+		/// Pays out the pool of the current season to its leaderboard according to
+		/// `SeasonPrizeShares`, keeps its results and starts the next season. Returns the weight
+		/// consumed.
+		fn end_season(now: T::BlockNumber) -> Weight {
+			let season = CurrentSeason::<T, I>::get();
+			let leaderboard = Leaderboard::<T, I>::take().into_inner();
+			let pool = SeasonPool::<T, I>::get();
+
+			let account_id = Self::account_id();
+			let mut prizes: Vec<(T::AccountId, BalanceOf<T, I>)> = Vec::new();
+			let mut paid = BalanceOf::<T, I>::zero();
+			for ((player, _), share) in leaderboard.iter().zip(T::SeasonPrizeShares::get()) {
+				let prize = share.mul_floor(pool);
+				if prize.is_zero() {
+					continue;
+				}
+
+				if T::Currency::transfer(
+					&account_id,
+					player,
+					prize,
+					ExistenceRequirement::AllowDeath,
+				)
+				.is_ok()
+				{
+					paid = paid.saturating_add(prize);
+					prizes.push((player.clone(), prize));
+				}
+			}
+			let winners = prizes.len() as u32;
+
+			SeasonPool::<T, I>::put(pool.saturating_sub(paid));
+			SeasonResults::<T, I>::insert(
+				season,
+				SeasonResult { ended_at: now, leaderboard, prizes: prizes.clone() },
+			);
+			CurrentSeason::<T, I>::put(season.saturating_add(1));
+
+			Self::deposit_event(Event::SeasonEnded { season, prizes });
+
+			T::WeightInfo::end_season(winners)
+		}
+
+		/// Moves the ratings of the players of a finished challenge according to its outcome, a
+		/// draw counting as half a win for each of them.
+		fn update_ratings(
+			challenger: &T::AccountId,
+			rival: &T::AccountId,
+			winner: Option<&T::AccountId>,
+		) {
+			let challenger_score = match winner {
+				Some(winner) if winner == challenger => Permill::one(),
+				Some(_) => Permill::zero(),
+				None => Permill::from_percent(50),
+			};
+			let (challenger_rating, rival_rating) = rating::updated_ratings(
+				Ratings::<T, I>::get(challenger),
+				Ratings::<T, I>::get(rival),
+				challenger_score,
+				T::KFactor::get(),
+			);
+			Ratings::<T, I>::insert(challenger, challenger_rating);
+			Ratings::<T, I>::insert(rival, rival_rating);
+		}
+
+		/// Returns the state of the given challenge.
+		pub fn challenge(challenge_id: ChallengeId) -> Option<ChallengeStateOf<T, I>> {
+			ChallengeStore::<T, I>::get(challenge_id)
+		}
+
+		/// Returns up to `count` open challenges, in ascending identifier order, starting from the
+		/// identifier `start` and looking at no more than `MAX_SCANNED_CHALLENGES` identifiers.
+		/// Also returns the identifier to resume from, or `None` once every identifier was looked
+		/// at.
+		pub fn open_challenges(
+			start: ChallengeId,
+			count: u32,
+		) -> (Vec<(ChallengeId, ChallengeStateOf<T, I>)>, Option<ChallengeId>) {
+			let next_challenge_id = NextBetId::<T, I>::get();
+			let end = start.saturating_add(MAX_SCANNED_CHALLENGES).min(next_challenge_id);
+
+			let mut challenges = Vec::new();
+			let mut challenge_id = start;
+			while challenge_id < end && challenges.len() < count as usize {
+				if let Some(challenge @ ChallengeState::Open(_)) =
+					ChallengeStore::<T, I>::get(challenge_id)
+				{
+					challenges.push((challenge_id, challenge));
+				}
+				challenge_id += 1;
+			}
+
+			(challenges, Some(challenge_id).filter(|id| *id < next_challenge_id))
+		}
+
+		/// Returns the statistics of the given account over the challenges it has finished.
+		pub fn stats_of(account: &T::AccountId) -> PlayerStatisticsOf<T, I> {
+			PlayerStats::<T, I>::get(account)
+		}
+
+		/// Returns the points of the given account in the current season.
+		pub fn current_season_points(account: &T::AccountId) -> SeasonPoints {
+			match PlayerSeasonPoints::<T, I>::get(account) {
+				(season, points) if season == CurrentSeason::<T, I>::get() => points,
+				_ => 0,
+			}
+		}
+
+		/// Returns every challenge in which the given account takes part.
+		pub fn challenges_of(account: &T::AccountId) -> Vec<(ChallengeId, ChallengeStateOf<T, I>)> {
+			PlayerChallenges::<T, I>::iter_key_prefix(account)
+				.filter_map(|challenge_id| {
+					ChallengeStore::<T, I>::get(challenge_id)
+						.map(|challenge| (challenge_id, challenge))
+				})
+				.collect()
+		}
+
+		/// Returns the commitment status of a player in the current round of a challenge it has
+		/// accepted, or in the last round of a finished one, or `None` if the player does not take
+		/// part in it.
+		pub fn commitment_status(
+			challenge_id: ChallengeId,
+			player: &T::AccountId,
+		) -> Option<CommitmentStatus<HandOf<T, I>>> {
+			match ChallengeStore::<T, I>::get(challenge_id)? {
+				ChallengeState::Open(_) => None,
+				ChallengeState::Accepted(accepted) if accepted.contains_player(player) => {
+					let round = accepted.round;
+					if let Some(hand) =
+						ChallengeRevealsStore::<T, I>::get((challenge_id, round, player))
+					{
+						Some(CommitmentStatus::Revealed(hand))
+					} else if ChallengePlaysStore::<T, I>::contains_key((
+						challenge_id,
+						round,
+						player,
+					)) {
+						Some(CommitmentStatus::Committed)
+					} else {
+						Some(CommitmentStatus::NotCommitted)
+					}
+				},
+				ChallengeState::Accepted(_) => None,
+				ChallengeState::Finished(finished) => finished.hand_of(player).map(|hand| {
+					hand.map_or(CommitmentStatus::Unrevealed, CommitmentStatus::Revealed)
+				}),
+			}
+		}
 	}
 }
@@ -0,0 +1,85 @@
+//! Storage migrations of the pallet.
+
+use crate::{BalanceOf, ChallengeId, Config, Pallet};
+use frame_support::{
+	pallet_prelude::*,
+	storage::migration::{have_storage_value, remove_storage_prefix, storage_key_iter},
+	traits::{Currency, GetStorageVersion, PalletInfoAccess, ReservableCurrency, StorageVersion},
+	StorageHasher,
+};
+use sp_std::vec::Vec;
+
+/// Migration from the unversioned layout of the first release, in which challenges were played
+/// in a single round without deposits, ratings or assets.
+pub mod v1 {
+	use super::*;
+
+	/// Challenge as stored in the unversioned layout.
+	#[allow(dead_code)]
+	#[derive(Decode)]
+	enum OldChallengeState<AccountId, Balance> {
+		Open {
+			challenger: AccountId,
+			bet_amount: Balance,
+		},
+		Accepted {
+			challenger: AccountId,
+			rival: AccountId,
+			bet_amount: Balance,
+		},
+		Finished {
+			challenger: AccountId,
+			rival: AccountId,
+			bet_amount: Balance,
+			winner: Option<AccountId>,
+		},
+	}
+
+	/// Key of a commitment in the unversioned `ChallengePlaysStore`, a double map by challenge
+	/// and player.
+	fn old_play_key<AccountId: Encode>(challenge_id: ChallengeId, player: &AccountId) -> Vec<u8> {
+		let mut key = Blake2_128Concat::hash(&challenge_id.encode());
+		key.extend(Blake2_128Concat::hash(&player.encode()));
+		key
+	}
+
+	/// Drains the challenges of the unversioned layout, which cannot be decoded anymore. Accepted
+	/// challenges are called off, giving back the bets reserved by the players who played in
+	/// them, while new challenges keep counting from `NextBetId`. The eight byte commitments of
+	/// the old `ChallengePlaysStore` are then cleared, as its prefix is shared with the new map.
+	pub fn migrate<T: Config<I>, I: 'static>() -> Weight {
+		if Pallet::<T, I>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let pallet = <Pallet<T, I> as PalletInfoAccess>::name().as_bytes();
+		let (mut reads, mut writes) = (1, 1);
+		for (challenge_id, challenge) in storage_key_iter::<
+			ChallengeId,
+			OldChallengeState<T::AccountId, BalanceOf<T, I>>,
+			Blake2_128Concat,
+		>(pallet, b"ChallengeStore")
+		.drain()
+		{
+			reads += 1;
+			writes += 1;
+			if let OldChallengeState::Accepted { challenger, rival, bet_amount } = challenge {
+				for player in [challenger, rival] {
+					reads += 1;
+					let key = old_play_key(challenge_id, &player);
+					if have_storage_value(pallet, b"ChallengePlaysStore", &key) {
+						writes += 1;
+						T::Currency::unreserve(&player, bet_amount);
+					}
+				}
+			}
+		}
+
+		remove_storage_prefix(pallet, b"ChallengePlaysStore", &[]);
+		writes += 1;
+
+		StorageVersion::new(1).put::<Pallet<T, I>>();
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
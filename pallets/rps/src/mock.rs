@@ -1,14 +1,56 @@
 use crate as pallet_rps;
-use frame_system as system;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, Currency, OnUnbalanced, Randomness},
+	PalletId,
+};
+use frame_system::{self as system, EnsureRoot};
+use pallet_balances::NegativeImbalance;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+/// Account without enough funds to cover the minimum bet.
+pub const DAVE: u64 = 4;
+pub const EVE: u64 = 5;
+/// Account receiving the fees taken from the winnings.
+pub const TREASURY: u64 = 99;
+
+pub const INITIAL_BALANCE: u64 = 1_000;
+pub const MIN_BET_AMOUNT: u64 = 10;
+pub const PLAY_TIMEOUT: u64 = 10;
+pub const REVEAL_TIMEOUT: u64 = 5;
+pub const CHALLENGE_DEPOSIT: u64 = 5;
+pub const FINISHED_RETENTION: u64 = 20;
+pub const MAX_ACTIVE_CHALLENGES: u32 = 5;
+pub const MAX_LOBBY_SIZE: u32 = 8;
+pub const HIGH_STAKES_MIN_BET_AMOUNT: u64 = 200;
+pub const HIGH_STAKES_RAKE_PERCENT: u32 = 10;
+/// Asset accepted for bets once its minimum bet is set.
+pub const ASSET_ID: u32 = 7;
+/// Asset that is never accepted for bets.
+pub const OTHER_ASSET_ID: u32 = 8;
+pub const ASSET_MIN_BET_AMOUNT: u64 = 50;
+pub const STARTING_RATING: u32 = 1_500;
+pub const K_FACTOR: u32 = 32;
+pub const MAX_TOURNAMENT_PLAYERS: u32 = 8;
+pub const MAX_ACTIVE_TOURNAMENTS: u32 = 2;
+pub const TOURNAMENT_DEPOSIT: u64 = 20;
+pub const MAX_REGISTRATION_PERIOD: u64 = 50;
+pub const SEASON_LENGTH: u64 = 100;
+pub const LEADERBOARD_SIZE: u32 = 3;
+pub const MAX_HOUSE_EXPOSURE_PERCENT: u32 = 50;
+pub const MAX_HOUSE_GAMES_PER_BLOCK: u32 = 2;
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test where
@@ -17,7 +59,10 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		TemplateModule: pallet_rps::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Config<T>, Event<T>},
+		RpsModule: pallet_rps::{Pallet, Call, Storage, Event<T>},
+		HighStakes: pallet_rps::<Instance1>::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -39,20 +84,180 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_assets::Config for Test {
+	type Event = Event;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const RpsPalletId: PalletId = PalletId(*b"py/rpsgm");
+	pub const HighStakesPalletId: PalletId = PalletId(*b"py/rpshs");
+	pub const NoRake: Permill = Permill::zero();
+	pub const HighStakesRake: Permill = Permill::from_percent(HIGH_STAKES_RAKE_PERCENT);
+	pub SeasonPrizeShares: Vec<Permill> = vec![Permill::from_percent(60), Permill::from_percent(40)];
+	pub const SeasonRakeShare: Permill = Permill::from_percent(50);
+	pub const MaxHouseExposure: Permill = Permill::from_percent(MAX_HOUSE_EXPOSURE_PERCENT);
+	/// Seed returned by `TestRandomness`, picking the hands drawn by the house.
+	pub static HouseSeed: u32 = 0;
+	/// Whether `TestRandomness` hashes the subject instead of returning `HouseSeed`.
+	pub static HashSubjects: bool = false;
+}
+
+/// Credits the fees taken from the winnings to the treasury account.
+pub struct FeesToTreasury;
+
+impl OnUnbalanced<NegativeImbalance<Test>> for FeesToTreasury {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance<Test>) {
+		Balances::resolve_creating(&TREASURY, amount);
+	}
 }
 
 impl pallet_rps::Config for Test {
 	type Event = Event;
+	type Currency = Balances;
+	type MinBetAmount = ConstU64<MIN_BET_AMOUNT>;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = RpsPalletId;
+	type AssetFeeCollector = ConstU64<TREASURY>;
+	type PlayTimeout = ConstU64<PLAY_TIMEOUT>;
+	type RevealTimeout = ConstU64<REVEAL_TIMEOUT>;
+	type ChallengeDeposit = ConstU64<CHALLENGE_DEPOSIT>;
+	type FinishedRetention = ConstU64<FINISHED_RETENTION>;
+	type MaxActiveChallengesPerAccount = ConstU32<MAX_ACTIVE_CHALLENGES>;
+	type MaxLobbySize = ConstU32<MAX_LOBBY_SIZE>;
+	type GameRules = pallet_rps::ClassicRules;
+	type RakePercent = NoRake;
+	type FeeDestination = FeesToTreasury;
+	type StartingRating = ConstU32<STARTING_RATING>;
+	type KFactor = ConstU32<K_FACTOR>;
+	type MaxTournamentPlayers = ConstU32<MAX_TOURNAMENT_PLAYERS>;
+	type MaxPrizeTiers = ConstU32<4>;
+	type MaxActiveTournaments = ConstU32<MAX_ACTIVE_TOURNAMENTS>;
+	type TournamentDeposit = ConstU64<TOURNAMENT_DEPOSIT>;
+	type MaxRegistrationPeriod = ConstU64<MAX_REGISTRATION_PERIOD>;
+	type SeasonLength = ConstU64<SEASON_LENGTH>;
+	type LeaderboardSize = ConstU32<LEADERBOARD_SIZE>;
+	type SeasonPrizeShares = SeasonPrizeShares;
+	type SeasonRakeShare = SeasonRakeShare;
+	type Randomness = TestRandomness;
+	type MaxHouseExposure = MaxHouseExposure;
+	type MaxHouseGamesPerBlock = ConstU32<MAX_HOUSE_GAMES_PER_BLOCK>;
+	type WeightInfo = ();
+}
+
+impl pallet_rps::Config<pallet_rps::Instance1> for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type MinBetAmount = ConstU64<HIGH_STAKES_MIN_BET_AMOUNT>;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = HighStakesPalletId;
+	type AssetFeeCollector = ConstU64<TREASURY>;
+	type PlayTimeout = ConstU64<PLAY_TIMEOUT>;
+	type RevealTimeout = ConstU64<REVEAL_TIMEOUT>;
+	type ChallengeDeposit = ConstU64<CHALLENGE_DEPOSIT>;
+	type FinishedRetention = ConstU64<FINISHED_RETENTION>;
+	type MaxActiveChallengesPerAccount = ConstU32<MAX_ACTIVE_CHALLENGES>;
+	type MaxLobbySize = ConstU32<MAX_LOBBY_SIZE>;
+	type GameRules = pallet_rps::RpslsRules;
+	type RakePercent = HighStakesRake;
+	type FeeDestination = FeesToTreasury;
+	type StartingRating = ConstU32<STARTING_RATING>;
+	type KFactor = ConstU32<K_FACTOR>;
+	type MaxTournamentPlayers = ConstU32<MAX_TOURNAMENT_PLAYERS>;
+	type MaxPrizeTiers = ConstU32<4>;
+	type MaxActiveTournaments = ConstU32<MAX_ACTIVE_TOURNAMENTS>;
+	type TournamentDeposit = ConstU64<TOURNAMENT_DEPOSIT>;
+	type MaxRegistrationPeriod = ConstU64<MAX_REGISTRATION_PERIOD>;
+	type SeasonLength = ConstU64<SEASON_LENGTH>;
+	type LeaderboardSize = ConstU32<LEADERBOARD_SIZE>;
+	type SeasonPrizeShares = SeasonPrizeShares;
+	type SeasonRakeShare = SeasonRakeShare;
+	type Randomness = TestRandomness;
+	type MaxHouseExposure = MaxHouseExposure;
+	type MaxHouseGamesPerBlock = ConstU32<MAX_HOUSE_GAMES_PER_BLOCK>;
+	type WeightInfo = ();
+}
+
+/// Randomness whose seed is set by the tests through `HouseSeed`, or derived from the subject when
+/// `HashSubjects` is set.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		if HashSubjects::get() {
+			return (H256(sp_io::hashing::blake2_256(subject)), System::block_number());
+		}
+		let mut seed = [0; 32];
+		seed[..4].copy_from_slice(&HouseSeed::get().to_le_bytes());
+		(H256(seed), System::block_number())
+	}
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(BOB, INITIAL_BALANCE),
+			(CHARLIE, INITIAL_BALANCE),
+			(DAVE, MIN_BET_AMOUNT - 1),
+			(EVE, INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(ASSET_ID, TREASURY, true, 1), (OTHER_ASSET_ID, TREASURY, true, 1)],
+		metadata: vec![],
+		accounts: vec![
+			(ASSET_ID, ALICE, INITIAL_BALANCE),
+			(ASSET_ID, BOB, INITIAL_BALANCE),
+			(OTHER_ASSET_ID, ALICE, INITIAL_BALANCE),
+			(OTHER_ASSET_ID, BOB, INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	// Events are not deposited on the genesis block
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
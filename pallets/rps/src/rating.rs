@@ -0,0 +1,75 @@
+//! Elo ratings of the players, updated as their challenges finish.
+
+use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+pub type Rating = u32;
+
+/// Ratings a challenge admits rivals from, both bounds included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RatingBand {
+	pub min: Rating,
+	pub max: Rating,
+}
+
+impl RatingBand {
+	pub fn is_valid(&self) -> bool {
+		self.min <= self.max
+	}
+
+	pub fn contains(&self, rating: Rating) -> bool {
+		self.min <= rating && rating <= self.max
+	}
+}
+
+/// Rating differences above this one are counted as this one, as in FIDE ratings.
+const MAX_RATING_DIFFERENCE: u32 = 400;
+
+/// Step between the rating differences of `EXPECTED_SCORES`.
+const RATING_DIFFERENCE_STEP: u32 = 25;
+
+/// Expected score in parts per million of the higher rated player, for every multiple of
+/// `RATING_DIFFERENCE_STEP` up to `MAX_RATING_DIFFERENCE`, following `1 / (1 + 10^(-d / 400))`.
+const EXPECTED_SCORES: [u32; 17] = [
+	500_000, 535_916, 571_463, 606_288, 640_065, 672_510, 703_385, 732_507, 759_747, 785_027,
+	808_318, 829_633, 849_020, 866_557, 882_338, 896_477, 909_091,
+];
+
+/// Expected score of a player rated `rating` against a player rated `other`, interpolated
+/// linearly between the entries of `EXPECTED_SCORES`.
+pub fn expected_score(rating: Rating, other: Rating) -> Permill {
+	let difference = (rating.max(other) - rating.min(other)).min(MAX_RATING_DIFFERENCE);
+	let index = (difference / RATING_DIFFERENCE_STEP) as usize;
+	let remainder = difference % RATING_DIFFERENCE_STEP;
+
+	let mut expected = EXPECTED_SCORES[index];
+	if remainder > 0 {
+		expected += (EXPECTED_SCORES[index + 1] - EXPECTED_SCORES[index]) * remainder
+			/ RATING_DIFFERENCE_STEP;
+	}
+
+	if rating >= other {
+		Permill::from_parts(expected)
+	} else {
+		Permill::from_parts(1_000_000 - expected)
+	}
+}
+
+/// New ratings of two players after a game in which the first one scored `score`, one for a win
+/// and a half for a draw. The points won by one player are lost by the other, ratings not going
+/// below zero.
+pub fn updated_ratings(
+	rating: Rating,
+	other: Rating,
+	score: Permill,
+	k_factor: Rating,
+) -> (Rating, Rating) {
+	let surprise = score.deconstruct() as i64 - expected_score(rating, other).deconstruct() as i64;
+	// Rounded to the nearest point, away from zero on ties
+	let change = (k_factor as i64 * surprise * 2 + surprise.signum() * 1_000_000) / 2_000_000;
+
+	let apply = |rating: Rating, change: i64| (rating as i64 + change).max(0) as Rating;
+	(apply(rating, change), apply(other, -change))
+}
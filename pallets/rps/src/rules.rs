@@ -0,0 +1,157 @@
+//! Rules of the hand games that can be played in challenges.
+
+use crate::{ChallengePlay, PlayResult};
+use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::{vec, vec::Vec};
+
+/// Move set and outcome matrix of a hand game.
+///
+/// Hands are committed to and revealed with their SCALE encoding, so it must not change once the
+/// rules are used on chain.
+pub trait GameRules {
+	/// Moves players can choose from.
+	type Hand: Parameter + MaxEncodedLen + 'static;
+
+	/// Whether the hand belongs to the move set. Invalid hands are rejected when revealed.
+	fn is_valid(_hand: &Self::Hand) -> bool {
+		true
+	}
+
+	/// Outcome of `hand` against `other`, from the point of view of the player of `hand`.
+	fn outcome(hand: &Self::Hand, other: &Self::Hand) -> PlayResult;
+
+	/// Every valid hand, the hand of the house being drawn among them.
+	fn hands() -> Vec<Self::Hand>;
+
+	/// Returns a hand along with another hand it beats, to set up benchmarks.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn winning_pair() -> (Self::Hand, Self::Hand);
+}
+
+/// Outcome of cyclic games with an odd number of moves, in which every move beats the moves at an
+/// odd distance before it and loses against the moves at an even distance before it.
+pub fn cyclic_outcome(hand: u8, other: u8, hand_count: u8) -> PlayResult {
+	// Widened so that the sum cannot overflow for move sets of more than 128 hands
+	let (hand, other, hand_count) = (u16::from(hand), u16::from(other), u16::from(hand_count));
+	let distance = (hand_count + hand % hand_count - other % hand_count) % hand_count;
+	if distance == 0 {
+		PlayResult::Draw
+	} else if distance % 2 == 1 {
+		PlayResult::Win
+	} else {
+		PlayResult::Lose
+	}
+}
+
+/// Classic rock-paper-scissors.
+pub struct ClassicRules;
+
+impl GameRules for ClassicRules {
+	type Hand = ChallengePlay;
+
+	fn outcome(hand: &ChallengePlay, other: &ChallengePlay) -> PlayResult {
+		cyclic_outcome(hand.index(), other.index(), 3)
+	}
+
+	fn hands() -> Vec<ChallengePlay> {
+		vec![ChallengePlay::Rock, ChallengePlay::Paper, ChallengePlay::Scissors]
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn winning_pair() -> (ChallengePlay, ChallengePlay) {
+		(ChallengePlay::Rock, ChallengePlay::Scissors)
+	}
+}
+
+impl ChallengePlay {
+	fn index(&self) -> u8 {
+		match self {
+			ChallengePlay::Rock => 0,
+			ChallengePlay::Paper => 1,
+			ChallengePlay::Scissors => 2,
+		}
+	}
+}
+
+/// Hands of rock-paper-scissors-lizard-Spock.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RpslsPlay {
+	Rock,
+	Paper,
+	Scissors,
+	Spock,
+	Lizard,
+}
+
+impl RpslsPlay {
+	fn index(&self) -> u8 {
+		match self {
+			RpslsPlay::Rock => 0,
+			RpslsPlay::Paper => 1,
+			RpslsPlay::Scissors => 2,
+			RpslsPlay::Spock => 3,
+			RpslsPlay::Lizard => 4,
+		}
+	}
+}
+
+/// Rock-paper-scissors-lizard-Spock, where scissors cuts paper, paper covers rock, rock crushes
+/// lizard, lizard poisons Spock, Spock smashes scissors, scissors decapitates lizard, lizard eats
+/// paper, paper disproves Spock, Spock vaporizes rock and rock crushes scissors.
+pub struct RpslsRules;
+
+impl GameRules for RpslsRules {
+	type Hand = RpslsPlay;
+
+	fn outcome(hand: &RpslsPlay, other: &RpslsPlay) -> PlayResult {
+		cyclic_outcome(hand.index(), other.index(), 5)
+	}
+
+	fn hands() -> Vec<RpslsPlay> {
+		vec![
+			RpslsPlay::Rock,
+			RpslsPlay::Paper,
+			RpslsPlay::Scissors,
+			RpslsPlay::Spock,
+			RpslsPlay::Lizard,
+		]
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn winning_pair() -> (RpslsPlay, RpslsPlay) {
+		(RpslsPlay::Spock, RpslsPlay::Rock)
+	}
+}
+
+/// Hand of a cyclic game, identified by its index in the move set.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CyclicHand(pub u8);
+
+/// Cyclic game with `N` moves, following `cyclic_outcome`. `N` must be odd so that every move beats
+/// as many moves as it loses against, otherwise no hand is valid.
+pub struct CyclicRules<const N: u8>;
+
+impl<const N: u8> GameRules for CyclicRules<N> {
+	type Hand = CyclicHand;
+
+	fn is_valid(hand: &CyclicHand) -> bool {
+		N % 2 == 1 && hand.0 < N
+	}
+
+	fn outcome(hand: &CyclicHand, other: &CyclicHand) -> PlayResult {
+		cyclic_outcome(hand.0, other.0, N)
+	}
+
+	fn hands() -> Vec<CyclicHand> {
+		(0..N).map(CyclicHand).filter(Self::is_valid).collect()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn winning_pair() -> (CyclicHand, CyclicHand) {
+		(CyclicHand(1), CyclicHand(0))
+	}
+}
@@ -0,0 +1,46 @@
+//! Seasons of a fixed number of blocks, ranking the players by the points won in their challenges.
+
+use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+pub type SeasonId = u32;
+
+pub type SeasonPoints = u32;
+
+/// Points won for each challenge won during a season.
+pub const POINTS_PER_WIN: SeasonPoints = 3;
+
+/// Points won for each drawn challenge during a season. Lost challenges are worth none, so points
+/// never go down and the leaderboard can be kept up to date one player at a time.
+pub const POINTS_PER_DRAW: SeasonPoints = 1;
+
+/// Final standings of a season and the prizes paid out to its best players.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SeasonResult<AccountId, Balance, BlockNumber> {
+	pub ended_at: BlockNumber,
+	/// Players with the most points, best first.
+	pub leaderboard: Vec<(AccountId, SeasonPoints)>,
+	pub prizes: Vec<(AccountId, Balance)>,
+}
+
+/// Moves an account to its rank in a leaderboard sorted by decreasing points, below the accounts
+/// that reached the same points before it, keeping at most `size` entries.
+///
+/// As points never go down, an account that is not in the leaderboard has no more points than
+/// the accounts in it, so the leaderboard stays exact without looking at the other players.
+pub fn rank<AccountId: PartialEq>(
+	leaderboard: &mut Vec<(AccountId, SeasonPoints)>,
+	account: AccountId,
+	points: SeasonPoints,
+	size: usize,
+) {
+	leaderboard.retain(|(ranked, _)| *ranked != account);
+	let rank = leaderboard.partition_point(|(_, ranked_points)| *ranked_points >= points);
+	if rank < size {
+		leaderboard.insert(rank, (account, points));
+		leaderboard.truncate(size);
+	}
+}
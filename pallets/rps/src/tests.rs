@@ -1,20 +1,2619 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*, tournament, ChallengeId, ChallengePlay, ChallengePlayHash, ChallengePlaySalt,
+	ChallengePlaysStore, ChallengeRevealsStore, ChallengeRound, ChallengeState, Error,
+	Event as RpsEvent, NextBetId, PlayCommitment, PlayResult, SeasonResult, TournamentId,
+	TournamentState, WeightInfo, MAX_SCANNED_CHALLENGES,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::migration::{have_storage_value, put_storage_value},
+	traits::{Currency, GetStorageVersion, Hooks, ReservableCurrency, StorageVersion},
+	Blake2_128Concat, StorageHasher,
+};
+use sp_runtime::Permill;
+
+const BET_AMOUNT: u64 = 100;
+
+fn salt_of(player: u64) -> ChallengePlaySalt {
+	[player as u8; 32]
+}
+
+fn commitment(player: u64, challenge_id: ChallengeId, hand: ChallengePlay) -> ChallengePlayHash {
+	round_commitment(player, challenge_id, 0, hand)
+}
+
+fn round_commitment(
+	player: u64,
+	challenge_id: ChallengeId,
+	round: ChallengeRound,
+	hand: ChallengePlay,
+) -> ChallengePlayHash {
+	PlayCommitment::new(
+		salt_of(player),
+		hand,
+		challenge_id,
+		round,
+		player,
+		System::block_hash(0u64),
+	)
+	.hash()
+}
+
+fn free_balance(who: u64) -> u64 {
+	Balances::free_balance(who)
+}
+
+fn reserved_balance(who: u64) -> u64 {
+	Balances::reserved_balance(who)
+}
+
+/// Creates a challenge from `ALICE` that `BOB` has accepted.
+fn accepted_challenge() -> ChallengeId {
+	let challenge_id = RpsModule::next_challenge_id();
+	assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+	assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), challenge_id));
+	challenge_id
+}
+
+fn play(player: u64, challenge_id: ChallengeId, hand: ChallengePlay) {
+	assert_ok!(RpsModule::play_challenge(
+		Origin::signed(player),
+		challenge_id,
+		commitment(player, challenge_id, hand)
+	));
+}
+
+/// Plays and reveals the given hands of `ALICE` and `BOB` in a round of a challenge.
+fn play_round(
+	challenge_id: ChallengeId,
+	round: ChallengeRound,
+	alice_hand: ChallengePlay,
+	bob_hand: ChallengePlay,
+) {
+	for (player, hand) in [(ALICE, &alice_hand), (BOB, &bob_hand)] {
+		assert_ok!(RpsModule::play_challenge(
+			Origin::signed(player),
+			challenge_id,
+			round_commitment(player, challenge_id, round, hand.clone())
+		));
+	}
+	reveal(ALICE, challenge_id, alice_hand);
+	reveal(BOB, challenge_id, bob_hand);
+}
+
+fn reveal(player: u64, challenge_id: ChallengeId, hand: ChallengePlay) {
+	assert_ok!(RpsModule::reveal_play(Origin::signed(player), challenge_id, hand, salt_of(player)));
+}
+
+/// Creates an accepted challenge in which both players have played the given hands.
+fn played_challenge(alice_hand: ChallengePlay, bob_hand: ChallengePlay) -> ChallengeId {
+	let challenge_id = accepted_challenge();
+	play(ALICE, challenge_id, alice_hand);
+	play(BOB, challenge_id, bob_hand);
+	challenge_id
+}
+
+/// Event of a challenge between `ALICE` and `BOB` finishing with the given hands and winner.
+fn finished_event(
+	challenge_id: ChallengeId,
+	challenger_hand: Option<ChallengePlay>,
+	rival_hand: Option<ChallengePlay>,
+	winner: Option<u64>,
+) -> Event {
+	Event::RpsModule(RpsEvent::ChallengeFinished {
+		challenge_id,
+		challenger: ALICE,
+		rival: BOB,
+		challenger_hand,
+		rival_hand,
+		payout: if winner.is_some() { BET_AMOUNT } else { 0 },
+		rake: 0,
+		winner,
+	})
+}
+
+fn winner_of(challenge_id: ChallengeId) -> Option<u64> {
+	match RpsModule::challenge_store(challenge_id) {
+		Some(ChallengeState::Finished(finished)) => finished.winner,
+		_ => panic!("challenge {} is not finished", challenge_id),
+	}
+}
+
+#[test]
+fn beats_follows_classic_rules() {
+	use crate::PlayResult;
+	use ChallengePlay::*;
+
+	assert_eq!(Rock.beats(&Scissors), PlayResult::Win);
+	assert_eq!(Rock.beats(&Paper), PlayResult::Lose);
+	assert_eq!(Rock.beats(&Rock), PlayResult::Draw);
+	assert_eq!(Paper.beats(&Rock), PlayResult::Win);
+	assert_eq!(Paper.beats(&Scissors), PlayResult::Lose);
+	assert_eq!(Paper.beats(&Paper), PlayResult::Draw);
+	assert_eq!(Scissors.beats(&Paper), PlayResult::Win);
+	assert_eq!(Scissors.beats(&Rock), PlayResult::Lose);
+	assert_eq!(Scissors.beats(&Scissors), PlayResult::Draw);
+}
+
+#[test]
+fn rpsls_rules_follow_the_outcome_matrix() {
+	use crate::{GameRules, PlayResult, RpslsPlay::*, RpslsRules};
+
+	let wins = [
+		(Scissors, Paper),
+		(Paper, Rock),
+		(Rock, Lizard),
+		(Lizard, Spock),
+		(Spock, Scissors),
+		(Scissors, Lizard),
+		(Lizard, Paper),
+		(Paper, Spock),
+		(Spock, Rock),
+		(Rock, Scissors),
+	];
+	for (winner, loser) in wins {
+		assert_eq!(RpslsRules::outcome(&winner, &loser), PlayResult::Win);
+		assert_eq!(RpslsRules::outcome(&loser, &winner), PlayResult::Lose);
+	}
+	for hand in [Rock, Paper, Scissors, Spock, Lizard] {
+		assert_eq!(RpslsRules::outcome(&hand, &hand), PlayResult::Draw);
+	}
+}
+
+#[test]
+fn cyclic_rules_are_balanced() {
+	use crate::{CyclicHand, CyclicRules, GameRules, PlayResult};
+
+	type Rules = CyclicRules<7>;
+	for hand in 0..7 {
+		let outcomes: Vec<_> = (0..7)
+			.map(|other| Rules::outcome(&CyclicHand(hand), &CyclicHand(other)))
+			.collect();
+		assert_eq!(outcomes.iter().filter(|outcome| **outcome == PlayResult::Win).count(), 3);
+		assert_eq!(outcomes.iter().filter(|outcome| **outcome == PlayResult::Lose).count(), 3);
+		assert_eq!(outcomes[hand as usize], PlayResult::Draw);
+	}
+	assert_eq!(Rules::outcome(&CyclicHand(1), &CyclicHand(0)), PlayResult::Win);
+	assert_eq!(Rules::outcome(&CyclicHand(0), &CyclicHand(6)), PlayResult::Win);
+
+	assert!(Rules::is_valid(&CyclicHand(6)));
+	assert!(!Rules::is_valid(&CyclicHand(7)));
+	assert!(!CyclicRules::<4>::is_valid(&CyclicHand(0)));
+}
+
+#[test]
+fn cyclic_rules_support_large_move_sets() {
+	use crate::{CyclicHand, CyclicRules, GameRules, PlayResult};
+
+	type Rules = CyclicRules<201>;
+	assert_eq!(Rules::outcome(&CyclicHand(200), &CyclicHand(0)), PlayResult::Lose);
+	assert_eq!(Rules::outcome(&CyclicHand(0), &CyclicHand(200)), PlayResult::Win);
+	assert_eq!(Rules::outcome(&CyclicHand(200), &CyclicHand(199)), PlayResult::Win);
+	assert_eq!(Rules::outcome(&CyclicHand(200), &CyclicHand(200)), PlayResult::Draw);
+	for hand in [0, 127, 128, 200] {
+		let outcomes: Vec<_> = (0..201)
+			.map(|other| Rules::outcome(&CyclicHand(hand), &CyclicHand(other)))
+			.collect();
+		assert_eq!(outcomes.iter().filter(|outcome| **outcome == PlayResult::Win).count(), 100);
+		assert_eq!(outcomes.iter().filter(|outcome| **outcome == PlayResult::Lose).count(), 100);
+	}
+	assert_eq!(CyclicRules::<255>::outcome(&CyclicHand(254), &CyclicHand(0)), PlayResult::Lose);
+}
+
+#[test]
+fn cyclic_rules_with_three_hands_match_classic_rules() {
+	use crate::{ClassicRules, CyclicHand, CyclicRules, GameRules};
+	use ChallengePlay::*;
+
+	let classic = [Rock, Paper, Scissors];
+	for (i, hand) in classic.iter().enumerate() {
+		for (j, other) in classic.iter().enumerate() {
+			assert_eq!(
+				ClassicRules::outcome(hand, other),
+				CyclicRules::<3>::outcome(&CyclicHand(i as u8), &CyclicHand(j as u8))
+			);
+		}
+	}
+}
+
+#[test]
+fn commitment_is_bound_to_challenge_round_and_player() {
+	new_test_ext().execute_with(|| {
+		let hash = commitment(ALICE, 0, ChallengePlay::Rock);
+
+		assert_ne!(hash, commitment(ALICE, 1, ChallengePlay::Rock));
+		assert_ne!(hash, round_commitment(ALICE, 0, 1, ChallengePlay::Rock));
+		assert_ne!(hash, commitment(BOB, 0, ChallengePlay::Rock));
+		assert_ne!(hash, commitment(ALICE, 0, ChallengePlay::Paper));
+		assert!(RpsModule::verify_play(0, 0, &ALICE, &ChallengePlay::Rock, salt_of(ALICE), &hash));
+		assert!(!RpsModule::verify_play(0, 0, &ALICE, &ChallengePlay::Rock, salt_of(BOB), &hash));
+		assert!(!RpsModule::verify_play(0, 1, &ALICE, &ChallengePlay::Rock, salt_of(ALICE), &hash));
+	});
+}
+
+#[test]
+fn create_challenge_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+
+		assert_eq!(RpsModule::next_challenge_id(), 1);
+		assert!(matches!(
+			RpsModule::challenge_store(0),
+			Some(ChallengeState::Open(open)) if open.challenger == ALICE && open.bet_amount == BET_AMOUNT
+		));
+		assert_eq!(reserved_balance(ALICE), BET_AMOUNT + CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE - BET_AMOUNT - CHALLENGE_DEPOSIT);
+		System::assert_last_event(Event::RpsModule(RpsEvent::ChallengeCreated {
+			challenge_id: 0,
+			challenger: ALICE,
+			intended_rival: None,
+			asset: None,
+			bet_amount: BET_AMOUNT,
+			rounds: 1,
+		}));
+	});
+}
+
+#[test]
+fn create_challenge_fails_below_min_bet() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::create_challenge(Origin::signed(ALICE), None, MIN_BET_AMOUNT - 1, 1, None),
+			Error::<Test>::InsufficientBetAmount
+		);
+	});
+}
+
+#[test]
+fn create_challenge_fails_without_funds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::create_challenge(Origin::signed(DAVE), None, MIN_BET_AMOUNT, 1, None),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn cancel_challenge_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_ok!(RpsModule::cancel_challenge(Origin::signed(ALICE), 0));
+
+		assert!(RpsModule::challenge_store(0).is_none());
+		assert_eq!(reserved_balance(ALICE), 0);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE);
+		System::assert_last_event(Event::RpsModule(RpsEvent::ChallengeCancelled {
+			challenge_id: 0,
+			challenger: ALICE,
+			bet_amount: BET_AMOUNT,
+		}));
+	});
+}
+
+#[test]
+fn cancel_challenge_fails_for_invalid_challenges() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::cancel_challenge(Origin::signed(ALICE), 0),
+			Error::<Test>::ChallengeNotFound
+		);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_noop!(
+			RpsModule::cancel_challenge(Origin::signed(BOB), 0),
+			Error::<Test>::CannotCancelOthersChallenge
+		);
+
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+		assert_noop!(
+			RpsModule::cancel_challenge(Origin::signed(ALICE), 0),
+			Error::<Test>::CannotCancelNonOpenChallenge
+		);
+	});
+}
+
+#[test]
+fn enter_challenge_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+
+		assert!(matches!(
+			RpsModule::challenge_store(0),
+			Some(ChallengeState::Accepted(accepted))
+				if accepted.challenger == ALICE && accepted.rival == BOB && accepted.accepted_at == 1
+		));
+		assert_eq!(reserved_balance(BOB), BET_AMOUNT);
+		System::assert_last_event(Event::RpsModule(RpsEvent::EnteredChallenge {
+			challenge_id: 0,
+			challenger: ALICE,
+			rival: BOB,
+			bet_amount: BET_AMOUNT,
+		}));
+	});
+}
+
+#[test]
+fn enter_challenge_fails_for_invalid_challenges() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::enter_challenge(Origin::signed(BOB), 0),
+			Error::<Test>::ChallengeNotFound
+		);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_noop!(
+			RpsModule::enter_challenge(Origin::signed(ALICE), 0),
+			Error::<Test>::CannotChallengeOneself
+		);
+		assert_noop!(
+			RpsModule::enter_challenge(Origin::signed(DAVE), 0),
+			Error::<Test>::InsufficientFunds
+		);
+
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+		assert_noop!(
+			RpsModule::enter_challenge(Origin::signed(CHARLIE), 0),
+			Error::<Test>::ChallengeNotOpen
+		);
+	});
+}
+
+#[test]
+fn play_challenge_works() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = accepted_challenge();
+
+		play(ALICE, challenge_id, ChallengePlay::Rock);
+		assert_eq!(
+			ChallengePlaysStore::<Test>::get((challenge_id, 0, ALICE)),
+			Some(commitment(ALICE, challenge_id, ChallengePlay::Rock))
+		);
+		System::assert_last_event(Event::RpsModule(RpsEvent::PlayedInChallenge {
+			challenge_id,
+			round: 0,
+			player: ALICE,
+			play_hash: commitment(ALICE, challenge_id, ChallengePlay::Rock),
+		}));
+
+		System::set_block_number(3);
+		play(BOB, challenge_id, ChallengePlay::Paper);
+		System::assert_has_event(Event::RpsModule(RpsEvent::PlayedInChallenge {
+			challenge_id,
+			round: 0,
+			player: BOB,
+			play_hash: commitment(BOB, challenge_id, ChallengePlay::Paper),
+		}));
+		System::assert_last_event(Event::RpsModule(RpsEvent::ChallengeReadyForReveal {
+			challenge_id,
+			round: 0,
+			reveal_deadline: 3 + REVEAL_TIMEOUT,
+		}));
+		assert!(matches!(
+			RpsModule::challenge_store(challenge_id),
+			Some(ChallengeState::Accepted(accepted)) if accepted.reveal_started_at == Some(3)
+		));
+	});
+}
+
+#[test]
+fn play_challenge_fails_for_invalid_challenges() {
+	new_test_ext().execute_with(|| {
+		let hash = commitment(ALICE, 0, ChallengePlay::Rock);
+
+		assert_noop!(
+			RpsModule::play_challenge(Origin::signed(ALICE), 0, hash),
+			Error::<Test>::ChallengeNotFound
+		);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_noop!(
+			RpsModule::play_challenge(Origin::signed(ALICE), 0, hash),
+			Error::<Test>::ChallengeStateForbidsPlay
+		);
+
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+		assert_noop!(
+			RpsModule::play_challenge(Origin::signed(CHARLIE), 0, hash),
+			Error::<Test>::CannotPlayInNonParticipatingChallenge
+		);
+
+		assert_ok!(RpsModule::play_challenge(Origin::signed(ALICE), 0, hash));
+		assert_noop!(
+			RpsModule::play_challenge(Origin::signed(ALICE), 0, hash),
+			Error::<Test>::ChallengeAlreadyPlayed
+		);
+	});
+}
+
+#[test]
+fn reveal_play_pays_the_winner() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = played_challenge(ChallengePlay::Rock, ChallengePlay::Scissors);
+
+		reveal(BOB, challenge_id, ChallengePlay::Scissors);
+		assert_eq!(
+			ChallengeRevealsStore::<Test>::get((challenge_id, 0, BOB)),
+			Some(ChallengePlay::Scissors)
+		);
+		System::assert_last_event(Event::RpsModule(RpsEvent::PlayRevealed {
+			challenge_id,
+			round: 0,
+			player: BOB,
+			hand: ChallengePlay::Scissors,
+		}));
+
+		reveal(ALICE, challenge_id, ChallengePlay::Rock);
+		System::assert_has_event(Event::RpsModule(RpsEvent::PlayRevealed {
+			challenge_id,
+			round: 0,
+			player: ALICE,
+			hand: ChallengePlay::Rock,
+		}));
+		System::assert_last_event(finished_event(
+			challenge_id,
+			Some(ChallengePlay::Rock),
+			Some(ChallengePlay::Scissors),
+			Some(ALICE),
+		));
+
+		assert_eq!(winner_of(challenge_id), Some(ALICE));
+		assert_eq!(reserved_balance(ALICE), CHALLENGE_DEPOSIT);
+		assert_eq!(reserved_balance(BOB), 0);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE + BET_AMOUNT - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - BET_AMOUNT);
+		assert_eq!(ChallengePlaysStore::<Test>::iter_prefix_values((challenge_id,)).count(), 0);
+		assert_eq!(ChallengeRevealsStore::<Test>::iter_prefix_values((challenge_id,)).count(), 0);
+	});
+}
+
+#[test]
+fn reveal_play_charges_the_loser() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = played_challenge(ChallengePlay::Rock, ChallengePlay::Paper);
+
+		reveal(ALICE, challenge_id, ChallengePlay::Rock);
+		reveal(BOB, challenge_id, ChallengePlay::Paper);
+		System::assert_last_event(finished_event(
+			challenge_id,
+			Some(ChallengePlay::Rock),
+			Some(ChallengePlay::Paper),
+			Some(BOB),
+		));
+
+		assert_eq!(winner_of(challenge_id), Some(BOB));
+		assert_eq!(reserved_balance(ALICE), CHALLENGE_DEPOSIT);
+		assert_eq!(reserved_balance(BOB), 0);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE - BET_AMOUNT - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE + BET_AMOUNT);
+	});
+}
+
+#[test]
+fn reveal_play_refunds_on_draw() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = played_challenge(ChallengePlay::Paper, ChallengePlay::Paper);
+
+		reveal(ALICE, challenge_id, ChallengePlay::Paper);
+		reveal(BOB, challenge_id, ChallengePlay::Paper);
+		System::assert_last_event(finished_event(
+			challenge_id,
+			Some(ChallengePlay::Paper),
+			Some(ChallengePlay::Paper),
+			None,
+		));
+
+		assert_eq!(winner_of(challenge_id), None);
+		assert_eq!(reserved_balance(ALICE), CHALLENGE_DEPOSIT);
+		assert_eq!(reserved_balance(BOB), 0);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn reveal_play_fails_for_invalid_reveals() {
+	new_test_ext().execute_with(|| {
+		let salt = salt_of(ALICE);
+
+		assert_noop!(
+			RpsModule::reveal_play(Origin::signed(ALICE), 0, ChallengePlay::Rock, salt),
+			Error::<Test>::ChallengeNotFound
+		);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_noop!(
+			RpsModule::reveal_play(Origin::signed(ALICE), 0, ChallengePlay::Rock, salt),
+			Error::<Test>::ChallengeStateForbidsResolution
+		);
+
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+		assert_noop!(
+			RpsModule::reveal_play(Origin::signed(CHARLIE), 0, ChallengePlay::Rock, salt),
+			Error::<Test>::CannotRevealNonParticipatingChallenge
+		);
+		assert_noop!(
+			RpsModule::reveal_play(Origin::signed(ALICE), 0, ChallengePlay::Rock, salt),
+			Error::<Test>::ChallengeNotReadyForReveal
+		);
+
+		play(ALICE, 0, ChallengePlay::Rock);
+		assert_noop!(
+			RpsModule::reveal_play(Origin::signed(ALICE), 0, ChallengePlay::Rock, salt),
+			Error::<Test>::ChallengeNotReadyForReveal
+		);
+
+		play(BOB, 0, ChallengePlay::Paper);
+		assert_noop!(
+			RpsModule::reveal_play(Origin::signed(ALICE), 0, ChallengePlay::Paper, salt),
+			Error::<Test>::InvalidHandHash
+		);
+		assert_noop!(
+			RpsModule::reveal_play(Origin::signed(ALICE), 0, ChallengePlay::Rock, salt_of(BOB)),
+			Error::<Test>::InvalidHandHash
+		);
+
+		reveal(ALICE, 0, ChallengePlay::Rock);
+		assert_noop!(
+			RpsModule::reveal_play(Origin::signed(ALICE), 0, ChallengePlay::Rock, salt),
+			Error::<Test>::ChallengeAlreadyRevealed
+		);
+	});
+}
+
+#[test]
+fn reveal_play_rejects_commitments_from_other_challenges() {
+	new_test_ext().execute_with(|| {
+		let first_id = accepted_challenge();
+		let second_id = accepted_challenge();
+
+		// Alice replays in the second challenge the commitment she sent to the first one
+		let hash = commitment(ALICE, first_id, ChallengePlay::Rock);
+		assert_ok!(RpsModule::play_challenge(Origin::signed(ALICE), second_id, hash));
+		play(BOB, second_id, ChallengePlay::Scissors);
+
+		assert_noop!(
+			RpsModule::reveal_play(
+				Origin::signed(ALICE),
+				second_id,
+				ChallengePlay::Rock,
+				salt_of(ALICE)
+			),
+			Error::<Test>::InvalidHandHash
+		);
+	});
+}
+
+#[test]
+fn reveal_challenge_results_works() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = played_challenge(ChallengePlay::Scissors, ChallengePlay::Paper);
+
+		assert_ok!(RpsModule::reveal_challenge_results(
+			Origin::signed(BOB),
+			ChallengePlay::Paper,
+			salt_of(BOB),
+			ChallengePlay::Scissors,
+			salt_of(ALICE),
+			challenge_id
+		));
+		System::assert_last_event(finished_event(
+			challenge_id,
+			Some(ChallengePlay::Scissors),
+			Some(ChallengePlay::Paper),
+			Some(ALICE),
+		));
+
+		assert_eq!(winner_of(challenge_id), Some(ALICE));
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE + BET_AMOUNT - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - BET_AMOUNT);
+	});
+}
+
+#[test]
+fn reveal_challenge_results_fails_for_invalid_reveals() {
+	new_test_ext().execute_with(|| {
+		let (alice_salt, bob_salt) = (salt_of(ALICE), salt_of(BOB));
+		let reveal_results = |who: u64, origin_hand, origin_salt, rival_hand, rival_salt| {
+			RpsModule::reveal_challenge_results(
+				Origin::signed(who),
+				origin_hand,
+				origin_salt,
+				rival_hand,
+				rival_salt,
+				0,
+			)
+		};
+		use ChallengePlay::*;
+
+		assert_noop!(
+			reveal_results(ALICE, Rock, alice_salt, Paper, bob_salt),
+			Error::<Test>::ChallengeNotFound
+		);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_noop!(
+			reveal_results(ALICE, Rock, alice_salt, Paper, bob_salt),
+			Error::<Test>::ChallengeStateForbidsPlay
+		);
+
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+		assert_noop!(
+			reveal_results(CHARLIE, Rock, alice_salt, Paper, bob_salt),
+			Error::<Test>::CannotPlayInNonParticipatingChallenge
+		);
+		assert_noop!(
+			reveal_results(ALICE, Rock, alice_salt, Paper, bob_salt),
+			Error::<Test>::ChallengeStateForbidsResolution
+		);
+
+		play(ALICE, 0, Rock);
+		assert_noop!(
+			reveal_results(ALICE, Rock, alice_salt, Paper, bob_salt),
+			Error::<Test>::ChallengeStateForbidsResolution
+		);
+
+		play(BOB, 0, Paper);
+		assert_noop!(
+			reveal_results(ALICE, Scissors, alice_salt, Paper, bob_salt),
+			Error::<Test>::InvalidHandHash
+		);
+		assert_noop!(
+			reveal_results(ALICE, Rock, alice_salt, Rock, bob_salt),
+			Error::<Test>::InvalidHandHash
+		);
+	});
+}
+
+#[test]
+fn claim_timeout_fails_before_deadline() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::claim_timeout(Origin::signed(CHARLIE), 0),
+			Error::<Test>::ChallengeNotFound
+		);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_noop!(
+			RpsModule::claim_timeout(Origin::signed(CHARLIE), 0),
+			Error::<Test>::ChallengeStateForbidsResolution
+		);
+
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+		System::set_block_number(PLAY_TIMEOUT);
+		assert_noop!(
+			RpsModule::claim_timeout(Origin::signed(CHARLIE), 0),
+			Error::<Test>::ChallengeNotTimedOut
+		);
+
+		play(ALICE, 0, ChallengePlay::Rock);
+		play(BOB, 0, ChallengePlay::Rock);
+		System::set_block_number(PLAY_TIMEOUT + REVEAL_TIMEOUT - 1);
+		assert_noop!(
+			RpsModule::claim_timeout(Origin::signed(CHARLIE), 0),
+			Error::<Test>::ChallengeNotTimedOut
+		);
+	});
+}
+
+#[test]
+fn claim_timeout_awards_the_player_that_played() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = accepted_challenge();
+		play(BOB, challenge_id, ChallengePlay::Rock);
+
+		System::set_block_number(1 + PLAY_TIMEOUT);
+		assert_ok!(RpsModule::claim_timeout(Origin::signed(CHARLIE), challenge_id));
+		System::assert_has_event(Event::RpsModule(RpsEvent::ChallengeTimedOut {
+			challenge_id,
+			forfeited: vec![ALICE],
+		}));
+		System::assert_last_event(finished_event(challenge_id, None, None, Some(BOB)));
+
+		assert_eq!(winner_of(challenge_id), Some(BOB));
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE - BET_AMOUNT - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE + BET_AMOUNT);
+	});
+}
+
+#[test]
+fn claim_timeout_awards_the_player_that_revealed() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = played_challenge(ChallengePlay::Rock, ChallengePlay::Paper);
+		reveal(ALICE, challenge_id, ChallengePlay::Rock);
+
+		System::set_block_number(1 + REVEAL_TIMEOUT);
+		assert_ok!(RpsModule::claim_timeout(Origin::signed(ALICE), challenge_id));
+		System::assert_has_event(Event::RpsModule(RpsEvent::ChallengeTimedOut {
+			challenge_id,
+			forfeited: vec![BOB],
+		}));
+		System::assert_last_event(finished_event(
+			challenge_id,
+			Some(ChallengePlay::Rock),
+			None,
+			Some(ALICE),
+		));
+
+		assert_eq!(winner_of(challenge_id), Some(ALICE));
+		assert_eq!(reserved_balance(BOB), 0);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE + BET_AMOUNT - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - BET_AMOUNT);
+	});
+}
+
+#[test]
+fn claim_timeout_refunds_when_nobody_played() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = accepted_challenge();
+
+		System::set_block_number(1 + PLAY_TIMEOUT);
+		assert_ok!(RpsModule::claim_timeout(Origin::signed(CHARLIE), challenge_id));
+		System::assert_has_event(Event::RpsModule(RpsEvent::ChallengeTimedOut {
+			challenge_id,
+			forfeited: vec![ALICE, BOB],
+		}));
+		System::assert_last_event(finished_event(challenge_id, None, None, None));
+
+		assert_eq!(winner_of(challenge_id), None);
+		assert_eq!(reserved_balance(ALICE), CHALLENGE_DEPOSIT);
+		assert_eq!(reserved_balance(BOB), 0);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE);
+
+		// A challenge nobody played is not counted as a draw
+		for player in [ALICE, BOB] {
+			assert_eq!(RpsModule::rating(player), STARTING_RATING);
+			assert_eq!(RpsModule::stats_of(&player), crate::PlayerStatistics::default());
+		}
+	});
+}
+
+#[test]
+fn open_challenges_are_paged_by_identifier() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..4 {
+			assert_ok!(RpsModule::create_challenge(
+				Origin::signed(ALICE),
+				None,
+				BET_AMOUNT,
+				1,
+				None
+			));
+		}
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 1));
+
+		let ids = |start, count| {
+			let (challenges, next) = RpsModule::open_challenges(start, count);
+			(challenges.into_iter().map(|(challenge_id, _)| challenge_id).collect::<Vec<_>>(), next)
+		};
+		assert_eq!(ids(0, 10), (vec![0, 2, 3], None));
+		assert_eq!(ids(0, 2), (vec![0, 2], Some(3)));
+		assert_eq!(ids(3, 2), (vec![3], None));
+	});
+}
+
+#[test]
+fn open_challenges_scan_a_bounded_number_of_identifiers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		NextBetId::<Test>::put(MAX_SCANNED_CHALLENGES + 1);
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+
+		let (challenges, next) = RpsModule::open_challenges(1, 10);
+		assert!(challenges.is_empty());
+		assert_eq!(next, Some(MAX_SCANNED_CHALLENGES + 1));
+
+		let (challenges, next) = RpsModule::open_challenges(MAX_SCANNED_CHALLENGES + 1, 10);
+		assert_eq!(challenges.len(), 1);
+		assert_eq!(next, None);
+	});
+}
+
+#[test]
+fn challenges_of_lists_every_challenge_of_an_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(BOB), None, BET_AMOUNT, 1, None));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(CHARLIE), 1));
+
+		let ids = |who| {
+			let mut ids = RpsModule::challenges_of(&who)
+				.into_iter()
+				.map(|(challenge_id, _)| challenge_id)
+				.collect::<Vec<_>>();
+			ids.sort();
+			ids
+		};
+		assert_eq!(ids(ALICE), vec![0]);
+		assert_eq!(ids(BOB), vec![1]);
+		assert_eq!(ids(CHARLIE), vec![1]);
+	});
+}
+
+#[test]
+fn commitment_status_follows_the_challenge_phases() {
+	new_test_ext().execute_with(|| {
+		use crate::CommitmentStatus;
+
+		assert_eq!(RpsModule::commitment_status(0, &ALICE), None);
+
+		let challenge_id = accepted_challenge();
+		assert_eq!(RpsModule::commitment_status(challenge_id, &CHARLIE), None);
+		assert_eq!(
+			RpsModule::commitment_status(challenge_id, &ALICE),
+			Some(CommitmentStatus::NotCommitted)
+		);
+
+		play(ALICE, challenge_id, ChallengePlay::Rock);
+		play(BOB, challenge_id, ChallengePlay::Paper);
+		assert_eq!(
+			RpsModule::commitment_status(challenge_id, &ALICE),
+			Some(CommitmentStatus::Committed)
+		);
+
+		reveal(ALICE, challenge_id, ChallengePlay::Rock);
+		assert_eq!(
+			RpsModule::commitment_status(challenge_id, &ALICE),
+			Some(CommitmentStatus::Revealed(ChallengePlay::Rock))
+		);
+
+		System::set_block_number(1 + REVEAL_TIMEOUT);
+		assert_ok!(RpsModule::claim_timeout(Origin::signed(ALICE), challenge_id));
+		assert_eq!(
+			RpsModule::commitment_status(challenge_id, &ALICE),
+			Some(CommitmentStatus::Revealed(ChallengePlay::Rock))
+		);
+		assert_eq!(
+			RpsModule::commitment_status(challenge_id, &BOB),
+			Some(CommitmentStatus::Unrevealed)
+		);
+		assert_eq!(RpsModule::commitment_status(challenge_id, &CHARLIE), None);
+	});
+}
+
+#[test]
+fn prune_challenge_rewards_the_pruner() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = played_challenge(ChallengePlay::Rock, ChallengePlay::Scissors);
+		reveal(ALICE, challenge_id, ChallengePlay::Rock);
+		reveal(BOB, challenge_id, ChallengePlay::Scissors);
+
+		System::set_block_number(1 + FINISHED_RETENTION);
+		assert_ok!(RpsModule::prune_challenge(Origin::signed(CHARLIE), challenge_id));
+
+		assert!(RpsModule::challenge_store(challenge_id).is_none());
+		assert_eq!(reserved_balance(ALICE), 0);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE + BET_AMOUNT - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(CHARLIE), INITIAL_BALANCE + CHALLENGE_DEPOSIT);
+		System::assert_last_event(Event::RpsModule(RpsEvent::ChallengePruned {
+			challenge_id,
+			pruner: CHARLIE,
+			reward: CHALLENGE_DEPOSIT,
+		}));
+	});
+}
+
+#[test]
+fn prune_challenge_fails_for_invalid_challenges() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::prune_challenge(Origin::signed(CHARLIE), 0),
+			Error::<Test>::ChallengeNotFound
+		);
+
+		let challenge_id = played_challenge(ChallengePlay::Rock, ChallengePlay::Scissors);
+		assert_noop!(
+			RpsModule::prune_challenge(Origin::signed(CHARLIE), challenge_id),
+			Error::<Test>::CannotPruneUnfinishedChallenge
+		);
+
+		reveal(ALICE, challenge_id, ChallengePlay::Rock);
+		reveal(BOB, challenge_id, ChallengePlay::Scissors);
+		System::set_block_number(FINISHED_RETENTION);
+		assert_noop!(
+			RpsModule::prune_challenge(Origin::signed(CHARLIE), challenge_id),
+			Error::<Test>::ChallengeRetentionNotElapsed
+		);
+	});
+}
+
+#[test]
+fn player_challenges_track_active_and_finished_challenges() {
+	new_test_ext().execute_with(|| {
+		let challenge_id = played_challenge(ChallengePlay::Rock, ChallengePlay::Scissors);
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert!(RpsModule::player_challenges(ALICE, challenge_id).is_some());
+		assert!(RpsModule::player_challenges(BOB, challenge_id).is_some());
+		assert_eq!(RpsModule::active_challenge_count(ALICE), 2);
+		assert_eq!(RpsModule::active_challenge_count(BOB), 1);
+
+		assert_ok!(RpsModule::cancel_challenge(Origin::signed(ALICE), challenge_id + 1));
+		assert!(RpsModule::player_challenges(ALICE, challenge_id + 1).is_none());
+		assert_eq!(RpsModule::active_challenge_count(ALICE), 1);
+
+		reveal(ALICE, challenge_id, ChallengePlay::Rock);
+		reveal(BOB, challenge_id, ChallengePlay::Scissors);
+		assert!(RpsModule::player_challenges(ALICE, challenge_id).is_some());
+		assert_eq!(RpsModule::active_challenge_count(ALICE), 0);
+		assert_eq!(RpsModule::active_challenge_count(BOB), 0);
+
+		System::set_block_number(1 + FINISHED_RETENTION);
+		assert_ok!(RpsModule::prune_challenge(Origin::signed(CHARLIE), challenge_id));
+		assert!(RpsModule::player_challenges(ALICE, challenge_id).is_none());
+		assert!(RpsModule::player_challenges(BOB, challenge_id).is_none());
+	});
+}
+
+#[test]
+fn active_challenges_are_bounded_per_account() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..MAX_ACTIVE_CHALLENGES {
+			assert_ok!(RpsModule::create_challenge(
+				Origin::signed(ALICE),
+				None,
+				BET_AMOUNT,
+				1,
+				None
+			));
+		}
+		assert_noop!(
+			RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None),
+			Error::<Test>::TooManyActiveChallenges
+		);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(BOB), None, BET_AMOUNT, 1, None));
+		assert_noop!(
+			RpsModule::enter_challenge(Origin::signed(ALICE), MAX_ACTIVE_CHALLENGES.into()),
+			Error::<Test>::TooManyActiveChallenges
+		);
+
+		assert_ok!(RpsModule::cancel_challenge(Origin::signed(ALICE), 0));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(ALICE), MAX_ACTIVE_CHALLENGES.into()));
+	});
+}
+
+#[test]
+fn lobby_lists_open_challenges_by_bet_amount() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, 300, 1, None));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(BOB), None, 100, 1, None));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, 200, 1, None));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(CHARLIE), None, 100, 1, None));
+		assert_eq!(
+			RpsModule::lobby().into_inner(),
+			vec![
+				(None, 100, 1, BOB, None),
+				(None, 100, 3, CHARLIE, None),
+				(None, 200, 2, ALICE, None),
+				(None, 300, 0, ALICE, None)
+			]
+		);
+
+		assert_ok!(RpsModule::cancel_challenge(Origin::signed(ALICE), 2));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(ALICE), 3));
+		assert_eq!(
+			RpsModule::lobby().into_inner(),
+			vec![(None, 100, 1, BOB, None), (None, 300, 0, ALICE, None)]
+		);
+	});
+}
+
+#[test]
+fn quick_join_enters_the_best_matching_challenge() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(BOB), None, 100, 1, None));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(CHARLIE), None, 100, 1, None));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, 200, 1, None));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(BOB), None, 300, 1, None));
+
+		assert_ok!(RpsModule::quick_join(Origin::signed(ALICE), None, 250));
+		System::assert_last_event(Event::RpsModule(RpsEvent::EnteredChallenge {
+			challenge_id: 0,
+			challenger: BOB,
+			rival: ALICE,
+			bet_amount: 100,
+		}));
+
+		assert_ok!(RpsModule::quick_join(Origin::signed(CHARLIE), None, 250));
+		System::assert_last_event(Event::RpsModule(RpsEvent::EnteredChallenge {
+			challenge_id: 2,
+			challenger: ALICE,
+			rival: CHARLIE,
+			bet_amount: 200,
+		}));
+		assert_eq!(
+			RpsModule::lobby().into_inner(),
+			vec![(None, 100, 1, CHARLIE, None), (None, 300, 3, BOB, None)]
+		);
+	});
+}
+
+#[test]
+fn quick_join_fails_without_matching_challenge() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::quick_join(Origin::signed(ALICE), None, BET_AMOUNT),
+			Error::<Test>::NoMatchingChallenge
+		);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(BOB), None, BET_AMOUNT + 1, 1, None));
+		assert_noop!(
+			RpsModule::quick_join(Origin::signed(ALICE), None, BET_AMOUNT),
+			Error::<Test>::NoMatchingChallenge
+		);
+	});
+}
+
+#[test]
+fn create_challenge_leaves_challenges_unlisted_when_lobby_is_full() {
+	new_test_ext().execute_with(|| {
+		for player in [ALICE, BOB] {
+			for _ in 0..MAX_LOBBY_SIZE / 2 {
+				assert_ok!(RpsModule::create_challenge(
+					Origin::signed(player),
+					None,
+					BET_AMOUNT,
+					1,
+					None
+				));
+			}
+		}
+		let challenge_id = RpsModule::next_challenge_id();
+		assert_ok!(RpsModule::create_challenge(Origin::signed(CHARLIE), None, BET_AMOUNT, 1, None));
+
+		assert_eq!(RpsModule::lobby().len(), MAX_LOBBY_SIZE as usize);
+		assert!(RpsModule::lobby().iter().all(|(_, _, id, _, _)| *id != challenge_id));
+		assert!(matches!(RpsModule::challenge_store(challenge_id), Some(ChallengeState::Open(_))));
+
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(EVE), challenge_id));
+		assert!(matches!(
+			RpsModule::challenge_store(challenge_id),
+			Some(ChallengeState::Accepted(_))
+		));
+	});
+}
+
+#[test]
+fn join_queue_matches_players_with_equal_stakes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::join_queue(Origin::signed(ALICE), None, BET_AMOUNT));
+		assert_eq!(
+			RpsModule::matchmaking_queue((None, BET_AMOUNT)),
+			Some((ALICE, CHALLENGE_DEPOSIT, 1))
+		);
+		assert_eq!(reserved_balance(ALICE), BET_AMOUNT + CHALLENGE_DEPOSIT);
+		System::assert_last_event(Event::RpsModule(RpsEvent::QueueJoined {
+			player: ALICE,
+			asset: None,
+			bet_amount: BET_AMOUNT,
+		}));
+
+		assert_ok!(RpsModule::join_queue(Origin::signed(BOB), None, BET_AMOUNT * 2));
+		assert_ok!(RpsModule::join_queue(Origin::signed(CHARLIE), None, BET_AMOUNT));
+		assert_eq!(RpsModule::matchmaking_queue((None, BET_AMOUNT)), None);
+		assert!(matches!(
+			RpsModule::challenge_store(0),
+			Some(ChallengeState::Accepted(accepted))
+				if accepted.challenger == ALICE && accepted.rival == CHARLIE
+		));
+		assert_eq!(reserved_balance(CHARLIE), BET_AMOUNT);
+		assert!(RpsModule::player_challenges(ALICE, 0).is_some());
+		assert!(RpsModule::player_challenges(CHARLIE, 0).is_some());
+		assert_eq!(RpsModule::active_challenge_count(ALICE), 1);
+		assert_eq!(RpsModule::active_challenge_count(CHARLIE), 1);
+		System::assert_last_event(Event::RpsModule(RpsEvent::EnteredChallenge {
+			challenge_id: 0,
+			challenger: ALICE,
+			rival: CHARLIE,
+			bet_amount: BET_AMOUNT,
+		}));
+	});
+}
+
+#[test]
+fn leave_queue_releases_the_stake() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::join_queue(Origin::signed(ALICE), None, BET_AMOUNT));
+		assert_ok!(RpsModule::leave_queue(Origin::signed(ALICE), None, BET_AMOUNT));
+
+		assert_eq!(RpsModule::matchmaking_queue((None, BET_AMOUNT)), None);
+		assert_eq!(reserved_balance(ALICE), 0);
+		assert_eq!(RpsModule::active_challenge_count(ALICE), 0);
+		System::assert_last_event(Event::RpsModule(RpsEvent::QueueLeft {
+			player: ALICE,
+			asset: None,
+			bet_amount: BET_AMOUNT,
+		}));
+	});
+}
+
+#[test]
+fn queue_fails_for_invalid_players() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::join_queue(Origin::signed(ALICE), None, MIN_BET_AMOUNT - 1),
+			Error::<Test>::InsufficientBetAmount
+		);
+		assert_noop!(
+			RpsModule::join_queue(Origin::signed(DAVE), None, MIN_BET_AMOUNT),
+			Error::<Test>::InsufficientFunds
+		);
+
+		assert_ok!(RpsModule::join_queue(Origin::signed(ALICE), None, BET_AMOUNT));
+		assert_noop!(
+			RpsModule::join_queue(Origin::signed(ALICE), None, BET_AMOUNT),
+			Error::<Test>::CannotChallengeOneself
+		);
+		assert_noop!(
+			RpsModule::leave_queue(Origin::signed(BOB), None, BET_AMOUNT),
+			Error::<Test>::NotInQueue
+		);
+		assert_noop!(
+			RpsModule::leave_queue(Origin::signed(ALICE), None, BET_AMOUNT * 2),
+			Error::<Test>::NotInQueue
+		);
+	});
+}
+
+#[test]
+fn create_challenge_for_only_admits_the_intended_rival() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge_for(
+			Origin::signed(ALICE),
+			BOB,
+			None,
+			BET_AMOUNT,
+			1
+		));
+		assert_eq!(reserved_balance(ALICE), BET_AMOUNT + CHALLENGE_DEPOSIT);
+		assert!(RpsModule::lobby().is_empty());
+		System::assert_last_event(Event::RpsModule(RpsEvent::ChallengeCreated {
+			challenge_id: 0,
+			challenger: ALICE,
+			intended_rival: Some(BOB),
+			asset: None,
+			bet_amount: BET_AMOUNT,
+			rounds: 1,
+		}));
+
+		assert_noop!(
+			RpsModule::enter_challenge(Origin::signed(CHARLIE), 0),
+			Error::<Test>::NotIntendedRival
+		);
+		assert_noop!(
+			RpsModule::quick_join(Origin::signed(CHARLIE), None, BET_AMOUNT),
+			Error::<Test>::NoMatchingChallenge
+		);
+
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+		assert!(matches!(
+			RpsModule::challenge_store(0),
+			Some(ChallengeState::Accepted(accepted)) if accepted.rival == BOB
+		));
+	});
+}
+
+#[test]
+fn create_challenge_for_fails_for_invalid_challenges() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::create_challenge_for(Origin::signed(ALICE), ALICE, None, BET_AMOUNT, 1),
+			Error::<Test>::CannotChallengeOneself
+		);
+		assert_noop!(
+			RpsModule::create_challenge_for(
+				Origin::signed(ALICE),
+				BOB,
+				None,
+				MIN_BET_AMOUNT - 1,
+				1
+			),
+			Error::<Test>::InsufficientBetAmount
+		);
+		assert_noop!(
+			RpsModule::create_challenge_for(Origin::signed(DAVE), BOB, None, MIN_BET_AMOUNT, 1),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn best_of_three_settles_once_a_player_wins_two_rounds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 3, None));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+
+		play_round(0, 0, ChallengePlay::Rock, ChallengePlay::Scissors);
+		System::assert_last_event(Event::RpsModule(RpsEvent::RoundFinished {
+			challenge_id: 0,
+			round: 0,
+			challenger_hand: ChallengePlay::Rock,
+			rival_hand: ChallengePlay::Scissors,
+			winner: Some(ALICE),
+			score: (1, 0),
+		}));
+
+		System::set_block_number(2);
+		play_round(0, 1, ChallengePlay::Rock, ChallengePlay::Rock);
+		System::assert_last_event(Event::RpsModule(RpsEvent::RoundFinished {
+			challenge_id: 0,
+			round: 1,
+			challenger_hand: ChallengePlay::Rock,
+			rival_hand: ChallengePlay::Rock,
+			winner: None,
+			score: (1, 0),
+		}));
+		assert!(matches!(
+			RpsModule::challenge_store(0),
+			Some(ChallengeState::Accepted(accepted))
+				if accepted.round == 2 && accepted.round_started_at == 2
+		));
+		assert_eq!(ChallengePlaysStore::<Test>::iter_prefix_values((0, 1)).count(), 0);
+
+		play_round(0, 2, ChallengePlay::Scissors, ChallengePlay::Rock);
+		play_round(0, 3, ChallengePlay::Paper, ChallengePlay::Rock);
+		System::assert_last_event(finished_event(
+			0,
+			Some(ChallengePlay::Paper),
+			Some(ChallengePlay::Rock),
+			Some(ALICE),
+		));
+		assert!(matches!(
+			RpsModule::challenge_store(0),
+			Some(ChallengeState::Finished(finished)) if finished.score == (2, 1)
+		));
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE + BET_AMOUNT - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - BET_AMOUNT);
+	});
+}
+
+#[test]
+fn claim_timeout_counts_from_the_start_of_the_round() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 3, None));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+
+		System::set_block_number(1 + PLAY_TIMEOUT);
+		play_round(0, 0, ChallengePlay::Rock, ChallengePlay::Scissors);
+		assert_noop!(
+			RpsModule::claim_timeout(Origin::signed(CHARLIE), 0),
+			Error::<Test>::ChallengeNotTimedOut
+		);
+
+		assert_ok!(RpsModule::play_challenge(
+			Origin::signed(BOB),
+			0,
+			round_commitment(BOB, 0, 1, ChallengePlay::Rock)
+		));
+		System::set_block_number(1 + 2 * PLAY_TIMEOUT);
+		assert_ok!(RpsModule::claim_timeout(Origin::signed(CHARLIE), 0));
+		assert_eq!(winner_of(0), Some(BOB));
+	});
+}
+
+#[test]
+fn create_challenge_fails_for_invalid_round_count() {
+	new_test_ext().execute_with(|| {
+		for rounds in [0, 2, 9] {
+			assert_noop!(
+				RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, rounds, None),
+				Error::<Test>::InvalidRoundCount
+			);
+		}
+	});
+}
+
+#[test]
+fn instances_keep_independent_challenges() {
+	use crate::{Instance1, RpslsPlay};
+
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HighStakes::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None),
+			Error::<Test, Instance1>::InsufficientBetAmount
+		);
+
+		let bet_amount = HIGH_STAKES_MIN_BET_AMOUNT;
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		assert_ok!(HighStakes::create_challenge(Origin::signed(ALICE), None, bet_amount, 1, None));
+		assert_ok!(HighStakes::enter_challenge(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::HighStakes(crate::Event::EnteredChallenge {
+			challenge_id: 0,
+			challenger: ALICE,
+			rival: BOB,
+			bet_amount,
+		}));
+		assert_eq!(HighStakes::next_challenge_id(), 1);
+		assert_eq!(RpsModule::active_challenge_count(BOB), 0);
+
+		let genesis_hash = System::block_hash(0u64);
+		for (player, hand) in [(ALICE, RpslsPlay::Spock), (BOB, RpslsPlay::Rock)] {
+			let play_hash =
+				PlayCommitment::new(salt_of(player), hand, 0, 0, player, genesis_hash).hash();
+			assert_ok!(HighStakes::play_challenge(Origin::signed(player), 0, play_hash));
+		}
+		assert_ok!(HighStakes::reveal_challenge_results(
+			Origin::signed(ALICE),
+			RpslsPlay::Spock,
+			salt_of(ALICE),
+			RpslsPlay::Rock,
+			salt_of(BOB),
+			0
+		));
+
+		assert!(matches!(
+			HighStakes::challenge_store(0),
+			Some(ChallengeState::Finished(finished)) if finished.winner == Some(ALICE)
+		));
+		assert!(matches!(RpsModule::challenge_store(0), Some(ChallengeState::Open(_))));
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - bet_amount);
+	});
+}
+
+/// Plays a single round challenge of rock-paper-scissors-lizard-Spock between Alice and Bob in the
+/// high stakes arena.
+fn high_stakes_challenge(alice_hand: crate::RpslsPlay, bob_hand: crate::RpslsPlay) -> ChallengeId {
+	let challenge_id = HighStakes::next_challenge_id();
+	assert_ok!(HighStakes::create_challenge(
+		Origin::signed(ALICE),
+		None,
+		HIGH_STAKES_MIN_BET_AMOUNT,
+		1,
+		None
+	));
+	assert_ok!(HighStakes::enter_challenge(Origin::signed(BOB), challenge_id));
+
+	let genesis_hash = System::block_hash(0u64);
+	for (player, hand) in [(ALICE, alice_hand.clone()), (BOB, bob_hand.clone())] {
+		let play_hash =
+			PlayCommitment::new(salt_of(player), hand, challenge_id, 0, player, genesis_hash)
+				.hash();
+		assert_ok!(HighStakes::play_challenge(Origin::signed(player), challenge_id, play_hash));
+	}
+	assert_ok!(HighStakes::reveal_challenge_results(
+		Origin::signed(ALICE),
+		alice_hand,
+		salt_of(ALICE),
+		bob_hand,
+		salt_of(BOB),
+		challenge_id
+	));
+	challenge_id
+}
+
+#[test]
+fn rake_is_taken_from_the_winnings() {
+	use crate::RpslsPlay;
+
+	new_test_ext().execute_with(|| {
+		let bet_amount = HIGH_STAKES_MIN_BET_AMOUNT;
+		let rake = bet_amount * HIGH_STAKES_RAKE_PERCENT as u64 / 100;
+
+		let challenge_id = high_stakes_challenge(RpslsPlay::Lizard, RpslsPlay::Paper);
+
+		System::assert_last_event(Event::HighStakes(crate::Event::ChallengeFinished {
+			challenge_id,
+			challenger: ALICE,
+			rival: BOB,
+			challenger_hand: Some(RpslsPlay::Lizard),
+			rival_hand: Some(RpslsPlay::Paper),
+			winner: Some(ALICE),
+			payout: bet_amount - rake,
+			rake,
+		}));
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE + bet_amount - rake - CHALLENGE_DEPOSIT);
+		assert_eq!(reserved_balance(ALICE), CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - bet_amount);
+		assert_eq!(reserved_balance(BOB), 0);
+		assert_eq!(free_balance(TREASURY), rake);
+	});
+}
+
+#[test]
+fn rake_is_not_taken_on_draws() {
+	use crate::RpslsPlay;
+
+	new_test_ext().execute_with(|| {
+		let challenge_id = high_stakes_challenge(RpslsPlay::Spock, RpslsPlay::Spock);
+
+		assert!(matches!(
+			HighStakes::challenge_store(challenge_id),
+			Some(ChallengeState::Finished(finished)) if finished.winner.is_none()
+		));
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE - CHALLENGE_DEPOSIT);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE);
+		assert_eq!(free_balance(TREASURY), 0);
+	});
+}
+
+fn asset_balance(asset_id: u32, who: u64) -> u64 {
+	Assets::balance(asset_id, who)
+}
+
+#[test]
+fn set_asset_min_bet_controls_accepted_assets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::create_challenge(Origin::signed(ALICE), Some(ASSET_ID), BET_AMOUNT, 1, None),
+			Error::<Test>::AssetNotAccepted
+		);
+		assert_noop!(
+			RpsModule::set_asset_min_bet(
+				Origin::signed(ALICE),
+				ASSET_ID,
+				Some(ASSET_MIN_BET_AMOUNT)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(RpsModule::set_asset_min_bet(
+			Origin::root(),
+			ASSET_ID,
+			Some(ASSET_MIN_BET_AMOUNT)
+		));
+		assert_eq!(RpsModule::asset_min_bet(ASSET_ID), Some(ASSET_MIN_BET_AMOUNT));
+		System::assert_last_event(Event::RpsModule(RpsEvent::AssetMinBetSet {
+			asset_id: ASSET_ID,
+			min_bet: Some(ASSET_MIN_BET_AMOUNT),
+		}));
+		assert_noop!(
+			RpsModule::join_queue(Origin::signed(ALICE), Some(ASSET_ID), ASSET_MIN_BET_AMOUNT - 1),
+			Error::<Test>::InsufficientBetAmount
+		);
+		assert_ok!(RpsModule::join_queue(
+			Origin::signed(ALICE),
+			Some(ASSET_ID),
+			ASSET_MIN_BET_AMOUNT
+		));
+
+		assert_ok!(RpsModule::set_asset_min_bet(Origin::root(), ASSET_ID, None));
+		assert_eq!(RpsModule::asset_min_bet(ASSET_ID), None);
+		assert_noop!(
+			RpsModule::join_queue(Origin::signed(BOB), Some(ASSET_ID), ASSET_MIN_BET_AMOUNT),
+			Error::<Test>::AssetNotAccepted
+		);
+	});
+}
+
+#[test]
+fn asset_bets_are_escrowed_and_paid_out() {
+	new_test_ext().execute_with(|| {
+		let escrow = RpsModule::account_id();
+		assert_ok!(RpsModule::set_asset_min_bet(
+			Origin::root(),
+			ASSET_ID,
+			Some(ASSET_MIN_BET_AMOUNT)
+		));
+
+		assert_ok!(RpsModule::create_challenge(
+			Origin::signed(ALICE),
+			Some(ASSET_ID),
+			BET_AMOUNT,
+			1,
+			None
+		));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+		assert_eq!(asset_balance(ASSET_ID, ALICE), INITIAL_BALANCE - BET_AMOUNT);
+		assert_eq!(asset_balance(ASSET_ID, BOB), INITIAL_BALANCE - BET_AMOUNT);
+		assert_eq!(asset_balance(ASSET_ID, escrow), BET_AMOUNT * 2);
+		// Only the deposit is held in the native currency
+		assert_eq!(reserved_balance(ALICE), CHALLENGE_DEPOSIT);
+		assert_eq!(reserved_balance(BOB), 0);
+
+		play_round(0, 0, ChallengePlay::Rock, ChallengePlay::Scissors);
+
+		assert_eq!(winner_of(0), Some(ALICE));
+		assert_eq!(asset_balance(ASSET_ID, ALICE), INITIAL_BALANCE + BET_AMOUNT);
+		assert_eq!(asset_balance(ASSET_ID, BOB), INITIAL_BALANCE - BET_AMOUNT);
+		assert_eq!(asset_balance(ASSET_ID, escrow), 0);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn asset_bets_are_refunded_on_draws() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::set_asset_min_bet(
+			Origin::root(),
+			ASSET_ID,
+			Some(ASSET_MIN_BET_AMOUNT)
+		));
+		assert_ok!(RpsModule::create_challenge(
+			Origin::signed(ALICE),
+			Some(ASSET_ID),
+			BET_AMOUNT,
+			1,
+			None
+		));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 0));
+
+		play_round(0, 0, ChallengePlay::Paper, ChallengePlay::Paper);
+
+		assert_eq!(winner_of(0), None);
+		assert_eq!(asset_balance(ASSET_ID, ALICE), INITIAL_BALANCE);
+		assert_eq!(asset_balance(ASSET_ID, BOB), INITIAL_BALANCE);
+		assert_eq!(asset_balance(ASSET_ID, RpsModule::account_id()), 0);
+	});
+}
+
+#[test]
+fn matchmaking_keeps_bet_assets_apart() {
+	new_test_ext().execute_with(|| {
+		for asset_id in [ASSET_ID, OTHER_ASSET_ID] {
+			assert_ok!(RpsModule::set_asset_min_bet(
+				Origin::root(),
+				asset_id,
+				Some(ASSET_MIN_BET_AMOUNT)
+			));
+		}
+		assert_ok!(RpsModule::create_challenge(
+			Origin::signed(ALICE),
+			Some(ASSET_ID),
+			BET_AMOUNT,
+			1,
+			None
+		));
+		assert_ok!(RpsModule::create_challenge(Origin::signed(CHARLIE), None, BET_AMOUNT, 1, None));
+		assert_eq!(
+			RpsModule::lobby().into_inner(),
+			vec![
+				(None, BET_AMOUNT, 1, CHARLIE, None),
+				(Some(ASSET_ID), BET_AMOUNT, 0, ALICE, None)
+			]
+		);
+
+		assert_noop!(
+			RpsModule::quick_join(Origin::signed(BOB), Some(OTHER_ASSET_ID), BET_AMOUNT),
+			Error::<Test>::NoMatchingChallenge
+		);
+		assert_ok!(RpsModule::quick_join(Origin::signed(BOB), Some(ASSET_ID), BET_AMOUNT));
+		System::assert_last_event(Event::RpsModule(RpsEvent::EnteredChallenge {
+			challenge_id: 0,
+			challenger: ALICE,
+			rival: BOB,
+			bet_amount: BET_AMOUNT,
+		}));
+
+		assert_ok!(RpsModule::join_queue(Origin::signed(ALICE), Some(ASSET_ID), BET_AMOUNT));
+		assert_ok!(RpsModule::join_queue(Origin::signed(BOB), Some(OTHER_ASSET_ID), BET_AMOUNT));
+		assert!(RpsModule::matchmaking_queue((Some(ASSET_ID), BET_AMOUNT)).is_some());
+		assert!(RpsModule::matchmaking_queue((Some(OTHER_ASSET_ID), BET_AMOUNT)).is_some());
+	});
+}
+
+#[test]
+fn expected_scores_follow_the_elo_curve() {
+	use crate::rating::expected_score;
+
+	assert_eq!(expected_score(1_500, 1_500), Permill::from_percent(50));
+	assert_eq!(expected_score(1_700, 1_500), Permill::from_parts(759_747));
+	assert_eq!(expected_score(1_500, 1_700), Permill::from_parts(240_253));
+	// Interpolated between the differences of 0 and 25
+	assert_eq!(expected_score(1_510, 1_500), Permill::from_parts(514_366));
+	// Differences above 400 count as 400
+	assert_eq!(expected_score(3_000, 1_000), expected_score(1_400, 1_000));
+}
+
+#[test]
+fn ratings_are_updated_when_challenges_finish() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(RpsModule::rating(ALICE), STARTING_RATING);
+
+		let challenge_id = accepted_challenge();
+		play_round(challenge_id, 0, ChallengePlay::Rock, ChallengePlay::Scissors);
+		assert_eq!(RpsModule::rating(ALICE), STARTING_RATING + K_FACTOR / 2);
+		assert_eq!(RpsModule::rating(BOB), STARTING_RATING - K_FACTOR / 2);
+		// Ratings are kept apart between instances
+		assert_eq!(HighStakes::rating(ALICE), STARTING_RATING);
+	});
+}
+
+#[test]
+fn draws_count_as_half_a_win() {
+	new_test_ext().execute_with(|| {
+		crate::Ratings::<Test>::insert(ALICE, 1_700);
+
+		let challenge_id = accepted_challenge();
+		play_round(challenge_id, 0, ChallengePlay::Paper, ChallengePlay::Paper);
+
+		// The lower rated player gains 32 * (0.5 - 0.24) points
+		assert_eq!(RpsModule::rating(ALICE), 1_692);
+		assert_eq!(RpsModule::rating(BOB), STARTING_RATING + 8);
+	});
+}
+
+#[test]
+fn rating_band_restricts_rivals() {
+	use crate::RatingBand;
+
+	new_test_ext().execute_with(|| {
+		let band = RatingBand { min: STARTING_RATING - 100, max: STARTING_RATING + 100 };
+		assert_noop!(
+			RpsModule::create_challenge(
+				Origin::signed(ALICE),
+				None,
+				BET_AMOUNT,
+				1,
+				Some(RatingBand { min: band.max, max: band.min })
+			),
+			Error::<Test>::InvalidRatingBand
+		);
+
+		crate::Ratings::<Test>::insert(CHARLIE, STARTING_RATING + 300);
+		assert_ok!(RpsModule::create_challenge(
+			Origin::signed(ALICE),
+			None,
+			BET_AMOUNT,
+			1,
+			Some(band)
+		));
+		assert_eq!(RpsModule::lobby().into_inner(), vec![(None, BET_AMOUNT, 0, ALICE, Some(band))]);
+
+		assert_noop!(
+			RpsModule::enter_challenge(Origin::signed(CHARLIE), 0),
+			Error::<Test>::RatingOutOfBand
+		);
+		assert_noop!(
+			RpsModule::quick_join(Origin::signed(CHARLIE), None, BET_AMOUNT),
+			Error::<Test>::NoMatchingChallenge
+		);
+		assert_ok!(RpsModule::quick_join(Origin::signed(BOB), None, BET_AMOUNT));
+		assert!(RpsModule::lobby().is_empty());
+	});
+}
+
+#[test]
+fn player_stats_are_updated_at_settlement() {
+	use crate::PlayerStatistics;
+
+	new_test_ext().execute_with(|| {
+		let challenge_id = played_challenge(ChallengePlay::Scissors, ChallengePlay::Paper);
+		assert_ok!(RpsModule::reveal_challenge_results(
+			Origin::signed(ALICE),
+			ChallengePlay::Scissors,
+			salt_of(ALICE),
+			ChallengePlay::Paper,
+			salt_of(BOB),
+			challenge_id
+		));
+
+		assert_eq!(
+			RpsModule::stats_of(&ALICE),
+			PlayerStatistics {
+				wins: 1,
+				losses: 0,
+				draws: 0,
+				total_wagered: BET_AMOUNT,
+				total_won: BET_AMOUNT,
+				total_lost: 0,
+				current_streak: 1,
+				longest_streak: 1,
+				hands_revealed: vec![(ChallengePlay::Scissors, 1)],
+			}
+		);
+		assert_eq!(
+			RpsModule::stats_of(&BOB),
+			PlayerStatistics {
+				wins: 0,
+				losses: 1,
+				draws: 0,
+				total_wagered: BET_AMOUNT,
+				total_won: 0,
+				total_lost: BET_AMOUNT,
+				current_streak: 0,
+				longest_streak: 0,
+				hands_revealed: vec![(ChallengePlay::Paper, 1)],
+			}
+		);
+		assert_eq!(RpsModule::stats_of(&CHARLIE), PlayerStatistics::default());
+	});
+}
+
+#[test]
+fn player_stats_track_streaks_and_hands_of_every_round() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..2 {
+			let challenge_id = accepted_challenge();
+			play_round(challenge_id, 0, ChallengePlay::Rock, ChallengePlay::Scissors);
+		}
+		assert_eq!(RpsModule::stats_of(&ALICE).current_streak, 2);
+
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 3, None));
+		assert_ok!(RpsModule::enter_challenge(Origin::signed(BOB), 2));
+		play_round(2, 0, ChallengePlay::Paper, ChallengePlay::Paper);
+		play_round(2, 1, ChallengePlay::Paper, ChallengePlay::Scissors);
+		play_round(2, 2, ChallengePlay::Rock, ChallengePlay::Paper);
+
+		let stats = RpsModule::stats_of(&ALICE);
+		assert_eq!((stats.wins, stats.losses, stats.draws), (2, 1, 0));
+		assert_eq!(stats.current_streak, 0);
+		assert_eq!(stats.longest_streak, 2);
+		assert_eq!(stats.total_wagered, BET_AMOUNT * 3);
+		assert_eq!(stats.total_won, BET_AMOUNT * 2);
+		assert_eq!(stats.total_lost, BET_AMOUNT);
+		assert_eq!(stats.hands_revealed, vec![(ChallengePlay::Rock, 3), (ChallengePlay::Paper, 2)]);
+		assert_eq!(
+			RpsModule::stats_of(&BOB).hands_revealed,
+			vec![(ChallengePlay::Scissors, 3), (ChallengePlay::Paper, 2)]
+		);
+	});
+}
+
+const ENTRY_FEE: u64 = 100;
+const REGISTRATION_DEADLINE: u64 = 5;
+
+/// Organizes a tournament from `ALICE` with prizes shared 60/30/10.
+fn create_tournament(max_players: u32) -> TournamentId {
+	let tournament_id = RpsModule::next_tournament_id();
+	assert_ok!(RpsModule::create_tournament(
+		Origin::signed(ALICE),
+		ENTRY_FEE,
+		max_players,
+		REGISTRATION_DEADLINE,
+		1,
+		vec![Permill::from_percent(60), Permill::from_percent(30), Permill::from_percent(10)]
+	));
+	tournament_id
+}
+
+fn run_to_block(block: u64) {
+	while System::block_number() < block {
+		System::set_block_number(System::block_number() + 1);
+		RpsModule::on_initialize(System::block_number());
+	}
+}
+
+/// Plays a single round tournament match to the end.
+fn play_match(challenge_id: ChallengeId, hands: [(u64, ChallengePlay); 2]) {
+	for (player, hand) in hands.iter().cloned() {
+		assert_ok!(RpsModule::play_challenge(
+			Origin::signed(player),
+			challenge_id,
+			round_commitment(player, challenge_id, 0, hand)
+		));
+	}
+	for (player, hand) in hands {
+		reveal(player, challenge_id, hand);
+	}
+}
+
+fn players_of(challenge_id: ChallengeId) -> Option<(u64, u64)> {
+	match RpsModule::challenge_store(challenge_id) {
+		Some(ChallengeState::Accepted(accepted)) => Some((accepted.challenger, accepted.rival)),
+		_ => None,
+	}
+}
+
+#[test]
+fn create_tournament_fails_for_invalid_settings() {
+	new_test_ext().execute_with(|| {
+		let shares = vec![Permill::one()];
+		for max_players in [1, MAX_TOURNAMENT_PLAYERS + 1] {
+			assert_noop!(
+				RpsModule::create_tournament(
+					Origin::signed(ALICE),
+					ENTRY_FEE,
+					max_players,
+					REGISTRATION_DEADLINE,
+					1,
+					shares.clone()
+				),
+				Error::<Test>::InvalidPlayerCap
+			);
+		}
+		for registration_deadline in [0, System::block_number() + MAX_REGISTRATION_PERIOD + 1] {
+			assert_noop!(
+				RpsModule::create_tournament(
+					Origin::signed(ALICE),
+					ENTRY_FEE,
+					4,
+					registration_deadline,
+					1,
+					shares.clone()
+				),
+				Error::<Test>::InvalidRegistrationDeadline
+			);
+		}
+		assert_noop!(
+			RpsModule::create_tournament(
+				Origin::signed(ALICE),
+				ENTRY_FEE,
+				4,
+				REGISTRATION_DEADLINE,
+				2,
+				shares.clone()
+			),
+			Error::<Test>::InvalidRoundCount
+		);
+		assert_noop!(
+			RpsModule::create_tournament(
+				Origin::signed(DAVE),
+				ENTRY_FEE,
+				4,
+				REGISTRATION_DEADLINE,
+				1,
+				shares
+			),
+			Error::<Test>::InsufficientFunds
+		);
+		for shares in [
+			vec![],
+			vec![Permill::from_percent(60), Permill::from_percent(30)],
+			vec![Permill::from_percent(20); 5],
+		] {
+			assert_noop!(
+				RpsModule::create_tournament(
+					Origin::signed(ALICE),
+					ENTRY_FEE,
+					4,
+					REGISTRATION_DEADLINE,
+					1,
+					shares
+				),
+				Error::<Test>::InvalidPrizeDistribution
+			);
+		}
+
+		for _ in 0..MAX_ACTIVE_TOURNAMENTS {
+			create_tournament(4);
+		}
+		assert_noop!(
+			RpsModule::create_tournament(
+				Origin::signed(ALICE),
+				ENTRY_FEE,
+				4,
+				REGISTRATION_DEADLINE,
+				1,
+				vec![Permill::one()]
+			),
+			Error::<Test>::TooManyActiveTournaments
+		);
+	});
+}
+
+#[test]
+fn join_tournament_collects_entry_fees() {
+	new_test_ext().execute_with(|| {
+		let tournament_id = create_tournament(2);
+		System::assert_last_event(Event::RpsModule(RpsEvent::TournamentCreated {
+			tournament_id,
+			organizer: ALICE,
+			entry_fee: ENTRY_FEE,
+			max_players: 2,
+			registration_deadline: REGISTRATION_DEADLINE,
+		}));
+		assert_eq!(reserved_balance(ALICE), TOURNAMENT_DEPOSIT);
+
+		assert_ok!(RpsModule::join_tournament(Origin::signed(BOB), tournament_id));
+		System::assert_last_event(Event::RpsModule(RpsEvent::TournamentJoined {
+			tournament_id,
+			player: BOB,
+		}));
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - ENTRY_FEE);
+		assert_eq!(free_balance(RpsModule::account_id()), ENTRY_FEE);
+
+		assert_noop!(
+			RpsModule::join_tournament(Origin::signed(BOB), tournament_id),
+			Error::<Test>::AlreadyRegistered
+		);
+		assert_noop!(
+			RpsModule::join_tournament(Origin::signed(DAVE), tournament_id),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_noop!(
+			RpsModule::join_tournament(Origin::signed(BOB), tournament_id + 1),
+			Error::<Test>::TournamentNotFound
+		);
+		assert_ok!(RpsModule::join_tournament(Origin::signed(CHARLIE), tournament_id));
+		assert_noop!(
+			RpsModule::join_tournament(Origin::signed(EVE), tournament_id),
+			Error::<Test>::TournamentFull
+		);
+
+		let tournament_id = create_tournament(4);
+		System::set_block_number(REGISTRATION_DEADLINE + 1);
+		assert_noop!(
+			RpsModule::join_tournament(Origin::signed(BOB), tournament_id),
+			Error::<Test>::RegistrationClosed
+		);
+	});
+}
+
+#[test]
+fn tournament_without_enough_players_is_cancelled() {
+	new_test_ext().execute_with(|| {
+		let tournament_id = create_tournament(4);
+		assert_ok!(RpsModule::join_tournament(Origin::signed(BOB), tournament_id));
+
+		run_to_block(REGISTRATION_DEADLINE + 1);
+
+		System::assert_last_event(Event::RpsModule(RpsEvent::TournamentCancelled {
+			tournament_id,
+		}));
+		assert_eq!(
+			RpsModule::tournament(tournament_id).map(|tournament| tournament.state),
+			Some(TournamentState::Cancelled)
+		);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE);
+		assert_eq!(reserved_balance(ALICE), 0);
+		assert_eq!(RpsModule::active_tournament_count(), 0);
+	});
+}
+
+#[test]
+fn tournament_is_played_to_the_end_and_pays_out_its_prizes() {
+	new_test_ext().execute_with(|| {
+		let tournament_id = create_tournament(4);
+		crate::Ratings::<Test>::insert(CHARLIE, STARTING_RATING + 100);
+		for player in [ALICE, BOB, CHARLIE, EVE] {
+			assert_ok!(RpsModule::join_tournament(Origin::signed(player), tournament_id));
+		}
+
+		// The best seed meets the worst one
+		run_to_block(REGISTRATION_DEADLINE + 1);
+		assert_eq!(players_of(0), Some((CHARLIE, EVE)));
+		assert_eq!(players_of(1), Some((ALICE, BOB)));
+		assert_eq!(
+			RpsModule::tournament(tournament_id).map(|tournament| tournament.state),
+			Some(TournamentState::Running(0))
+		);
+
+		play_match(0, [(CHARLIE, ChallengePlay::Rock), (EVE, ChallengePlay::Scissors)]);
+		// Drawn matches are replayed
+		play_match(1, [(ALICE, ChallengePlay::Paper), (BOB, ChallengePlay::Paper)]);
+		assert_eq!(players_of(2), Some((ALICE, BOB)));
+		play_match(2, [(ALICE, ChallengePlay::Rock), (BOB, ChallengePlay::Scissors)]);
+		assert_eq!(
+			RpsModule::tournament_bracket(tournament_id).into_inner(),
+			vec![Some(CHARLIE), Some(ALICE)]
+		);
+
+		run_to_block(System::block_number() + 1);
+		assert_eq!(players_of(3), Some((CHARLIE, ALICE)));
+
+		// Players who do not play forfeit their match
+		assert_ok!(RpsModule::play_challenge(
+			Origin::signed(CHARLIE),
+			3,
+			round_commitment(CHARLIE, 3, 0, ChallengePlay::Rock)
+		));
+		System::set_block_number(System::block_number() + PLAY_TIMEOUT);
+		assert_ok!(RpsModule::claim_timeout(Origin::signed(BOB), 3));
+
+		// 60% to the champion, 30% to the runner-up and 10% shared by the semi-finalists
+		let pool = ENTRY_FEE * 4;
+		System::assert_last_event(Event::RpsModule(RpsEvent::TournamentFinished {
+			tournament_id,
+			champion: CHARLIE,
+			prizes: vec![
+				(CHARLIE, pool * 60 / 100),
+				(ALICE, pool * 30 / 100),
+				(EVE, pool * 5 / 100),
+				(BOB, pool * 5 / 100),
+			],
+		}));
+		assert_eq!(
+			RpsModule::tournament(tournament_id).map(|tournament| tournament.state),
+			Some(TournamentState::Finished { champion: CHARLIE })
+		);
+		assert_eq!(free_balance(CHARLIE), INITIAL_BALANCE - ENTRY_FEE + pool * 60 / 100);
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE - ENTRY_FEE + pool * 30 / 100);
+		assert_eq!(reserved_balance(ALICE), 0);
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - ENTRY_FEE + pool * 5 / 100);
+		assert_eq!(free_balance(RpsModule::account_id()), 0);
+		assert_eq!(RpsModule::active_tournament_count(), 0);
+		assert_eq!(RpsModule::active_challenge_count(CHARLIE), 0);
+	});
+}
+
+#[test]
+fn settling_the_final_match_charges_the_tournament_payout() {
+	new_test_ext().execute_with(|| {
+		let tournament_id = create_tournament(2);
+		for player in [BOB, CHARLIE] {
+			assert_ok!(RpsModule::join_tournament(Origin::signed(player), tournament_id));
+		}
+		run_to_block(REGISTRATION_DEADLINE + 1);
+
+		System::set_block_number(System::block_number() + PLAY_TIMEOUT);
+		let post_info = RpsModule::claim_timeout(Origin::signed(EVE), 0).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::claim_timeout() + <() as WeightInfo>::finish_tournament(2))
+		);
+
+		// Challenges outside tournaments are refunded the weight of the payout
+		let challenge_id = played_challenge(ChallengePlay::Rock, ChallengePlay::Paper);
+		reveal(ALICE, challenge_id, ChallengePlay::Rock);
+		let post_info = RpsModule::reveal_play(
+			Origin::signed(BOB),
+			challenge_id,
+			ChallengePlay::Paper,
+			salt_of(BOB),
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::reveal_play()));
+	});
+}
+
+#[test]
+fn best_seed_gets_a_bye_with_an_odd_number_of_players() {
+	new_test_ext().execute_with(|| {
+		let tournament_id = create_tournament(4);
+		for player in [ALICE, BOB, CHARLIE] {
+			assert_ok!(RpsModule::join_tournament(Origin::signed(player), tournament_id));
+		}
+
+		run_to_block(REGISTRATION_DEADLINE + 1);
+		assert_eq!(players_of(0), Some((BOB, CHARLIE)));
+		assert_eq!(
+			RpsModule::tournament_bracket(tournament_id).into_inner(),
+			vec![Some(ALICE), None]
+		);
+
+		play_match(0, [(BOB, ChallengePlay::Rock), (CHARLIE, ChallengePlay::Paper)]);
+		run_to_block(System::block_number() + 1);
+		assert_eq!(players_of(1), Some((ALICE, CHARLIE)));
+	});
+}
+
+#[test]
+fn bracket_keeps_the_best_seeds_apart_until_the_final() {
+	assert_eq!(tournament::bracket_order(8), vec![0, 7, 3, 4, 1, 6, 2, 5]);
+	// Byes go to the best seeds, in the first round only
+	assert_eq!(
+		tournament::first_round_pairings(&[1, 2, 3, 4, 5]),
+		vec![(1, None), (4, Some(5)), (2, None), (3, None)]
+	);
+
+	new_test_ext().execute_with(|| {
+		let tournament_id = create_tournament(8);
+		// Seeds from the best to the worst
+		let seeds: Vec<u64> = (10..18).collect();
+		for (index, player) in seeds.iter().enumerate() {
+			let _ = Balances::deposit_creating(player, INITIAL_BALANCE);
+			crate::Ratings::<Test>::insert(player, STARTING_RATING + 100 - index as u32);
+			assert_ok!(RpsModule::join_tournament(Origin::signed(*player), tournament_id));
+		}
+		let seed = |rank: usize| seeds[rank - 1];
+
+		run_to_block(REGISTRATION_DEADLINE + 1);
+		assert_eq!(players_of(0), Some((seed(1), seed(8))));
+		assert_eq!(players_of(1), Some((seed(4), seed(5))));
+		assert_eq!(players_of(2), Some((seed(2), seed(7))));
+		assert_eq!(players_of(3), Some((seed(3), seed(6))));
+
+		// The best seed wins every match
+		for challenge_id in 0..4 {
+			let (challenger, rival) = players_of(challenge_id).unwrap();
+			play_match(
+				challenge_id,
+				[(challenger, ChallengePlay::Paper), (rival, ChallengePlay::Rock)],
+			);
+		}
+		run_to_block(System::block_number() + 1);
+		assert_eq!(players_of(4), Some((seed(1), seed(4))));
+		assert_eq!(players_of(5), Some((seed(2), seed(3))));
+
+		play_match(4, [(seed(1), ChallengePlay::Paper), (seed(4), ChallengePlay::Rock)]);
+		play_match(5, [(seed(2), ChallengePlay::Paper), (seed(3), ChallengePlay::Rock)]);
+		run_to_block(System::block_number() + 1);
+		assert_eq!(players_of(6), Some((seed(1), seed(2))));
+	});
+}
+
+/// Plays and reveals a single round challenge between `ALICE` and `BOB`.
+fn finished_challenge(alice_hand: ChallengePlay, bob_hand: ChallengePlay) -> ChallengeId {
+	let challenge_id = played_challenge(alice_hand.clone(), bob_hand.clone());
+	reveal(ALICE, challenge_id, alice_hand);
+	reveal(BOB, challenge_id, bob_hand);
+	challenge_id
+}
+
+#[test]
+fn season_rank_keeps_the_best_players_in_order() {
+	use crate::season::rank;
+
+	let mut leaderboard = vec![];
+	rank(&mut leaderboard, ALICE, 3, 3);
+	rank(&mut leaderboard, BOB, 1, 3);
+	rank(&mut leaderboard, CHARLIE, 3, 3);
+	assert_eq!(leaderboard, vec![(ALICE, 3), (CHARLIE, 3), (BOB, 1)]);
+
+	// Moving up replaces the previous entry of the account
+	rank(&mut leaderboard, BOB, 4, 3);
+	assert_eq!(leaderboard, vec![(BOB, 4), (ALICE, 3), (CHARLIE, 3)]);
+
+	// Accounts below the last rank of a full leaderboard are left out
+	rank(&mut leaderboard, EVE, 3, 3);
+	assert_eq!(leaderboard, vec![(BOB, 4), (ALICE, 3), (CHARLIE, 3)]);
+	rank(&mut leaderboard, EVE, 6, 3);
+	assert_eq!(leaderboard, vec![(EVE, 6), (BOB, 4), (ALICE, 3)]);
+}
+
+#[test]
+fn season_points_are_won_in_finished_challenges() {
+	new_test_ext().execute_with(|| {
+		finished_challenge(ChallengePlay::Paper, ChallengePlay::Rock);
+		assert_eq!(RpsModule::current_season_points(&ALICE), 3);
+		assert_eq!(RpsModule::current_season_points(&BOB), 0);
+		assert_eq!(RpsModule::leaderboard().into_inner(), vec![(ALICE, 3)]);
+
+		finished_challenge(ChallengePlay::Rock, ChallengePlay::Rock);
+		assert_eq!(RpsModule::current_season_points(&ALICE), 4);
+		assert_eq!(RpsModule::current_season_points(&BOB), 1);
+		assert_eq!(RpsModule::leaderboard().into_inner(), vec![(ALICE, 4), (BOB, 1)]);
+	});
+}
+
+#[test]
+fn fund_season_pool_requires_root() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RpsModule::fund_season_pool(Origin::signed(CHARLIE), CHARLIE, 500),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(RpsModule::fund_season_pool(Origin::root(), CHARLIE, 500));
+		System::assert_last_event(Event::RpsModule(RpsEvent::SeasonPoolFunded {
+			source: CHARLIE,
+			amount: 500,
+		}));
+		assert_eq!(RpsModule::season_pool(), 500);
+		assert_eq!(free_balance(CHARLIE), INITIAL_BALANCE - 500);
+		assert_eq!(free_balance(RpsModule::account_id()), 500);
+	});
+}
+
+#[test]
+fn season_rollover_pays_out_the_leaderboard() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::fund_season_pool(Origin::root(), CHARLIE, 500));
+		finished_challenge(ChallengePlay::Paper, ChallengePlay::Rock);
+		finished_challenge(ChallengePlay::Rock, ChallengePlay::Rock);
+		let (alice_balance, bob_balance) = (free_balance(ALICE), free_balance(BOB));
+
+		run_to_block(SEASON_LENGTH - 1);
+		assert_eq!(RpsModule::current_season(), 0);
+
+		run_to_block(SEASON_LENGTH);
+		let prizes = vec![(ALICE, 300), (BOB, 200)];
+		System::assert_last_event(Event::RpsModule(RpsEvent::SeasonEnded {
+			season: 0,
+			prizes: prizes.clone(),
+		}));
+		assert_eq!(
+			RpsModule::season_result(0),
+			Some(SeasonResult {
+				ended_at: SEASON_LENGTH,
+				leaderboard: vec![(ALICE, 4), (BOB, 1)],
+				prizes,
+			})
+		);
+		assert_eq!(free_balance(ALICE), alice_balance + 300);
+		assert_eq!(free_balance(BOB), bob_balance + 200);
+
+		assert_eq!(RpsModule::current_season(), 1);
+		assert_eq!(RpsModule::season_pool(), 0);
+		assert!(RpsModule::leaderboard().is_empty());
+		assert_eq!(RpsModule::current_season_points(&ALICE), 0);
+
+		// Points of the new season start from zero
+		finished_challenge(ChallengePlay::Rock, ChallengePlay::Paper);
+		assert_eq!(RpsModule::current_season_points(&BOB), 3);
+		assert_eq!(RpsModule::leaderboard().into_inner(), vec![(BOB, 3)]);
+	});
+}
+
+#[test]
+fn unpaid_prizes_are_carried_over_to_the_next_season() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RpsModule::fund_season_pool(Origin::root(), CHARLIE, 500));
+
+		run_to_block(SEASON_LENGTH);
+		assert_eq!(RpsModule::season_result(0).map(|result| result.prizes), Some(vec![]));
+		assert_eq!(RpsModule::season_pool(), 500);
+
+		finished_challenge(ChallengePlay::Paper, ChallengePlay::Rock);
+		run_to_block(2 * SEASON_LENGTH);
+		System::assert_last_event(Event::RpsModule(RpsEvent::SeasonEnded {
+			season: 1,
+			prizes: vec![(ALICE, 300)],
+		}));
+		assert_eq!(RpsModule::season_pool(), 200);
+		assert_eq!(free_balance(RpsModule::account_id()), 200);
+	});
+}
+
+#[test]
+fn rake_is_shared_with_the_season_pool() {
+	use crate::RpslsPlay;
+
+	new_test_ext().execute_with(|| {
+		let rake = HIGH_STAKES_MIN_BET_AMOUNT * HIGH_STAKES_RAKE_PERCENT as u64 / 100;
+		assert_ok!(HighStakes::fund_season_pool(Origin::root(), CHARLIE, 100));
+
+		high_stakes_challenge(RpslsPlay::Lizard, RpslsPlay::Paper);
+
+		assert_eq!(HighStakes::season_pool(), 100 + rake / 2);
+		assert_eq!(free_balance(HighStakes::account_id()), 100 + rake / 2);
+		assert_eq!(free_balance(TREASURY), rake - rake / 2);
+	});
+}
+
+#[test]
+fn hands_are_the_valid_hands_of_the_rules() {
+	use crate::{CyclicHand, CyclicRules, GameRules, RpslsRules};
+
+	assert_eq!(
+		crate::ClassicRules::hands(),
+		vec![ChallengePlay::Rock, ChallengePlay::Paper, ChallengePlay::Scissors]
+	);
+	assert_eq!(RpslsRules::hands().len(), 5);
+	assert_eq!(CyclicRules::<5>::hands(), (0..5).map(CyclicHand).collect::<Vec<_>>());
+	assert!(CyclicRules::<4>::hands().is_empty());
+}
+
+const HOUSE_BANKROLL: u64 = 400;
+
+/// Funds the bankroll of the house from `CHARLIE`.
+fn fund_house(amount: u64) {
+	assert_ok!(Balances::transfer(Origin::signed(CHARLIE), RpsModule::house_account_id(), amount));
+}
+
+/// Commitment of `player` to `hand` in its next game against the house.
+fn house_commitment(player: u64, hand: ChallengePlay) -> ChallengePlayHash {
+	PlayCommitment::house(
+		salt_of(player),
+		hand,
+		player,
+		RpsModule::house_nonce(player),
+		System::block_hash(0u64),
+	)
+	.hash()
+}
+
+/// Starts a game of `player` against the house, committing to `hand`.
+fn play_house(player: u64, hand: ChallengePlay) -> u64 {
+	let game_id = RpsModule::next_house_game_id();
+	assert_ok!(RpsModule::play_house(
+		Origin::signed(player),
+		house_commitment(player, hand),
+		BET_AMOUNT
+	));
+	game_id
+}
+
+#[test]
+fn play_house_reserves_the_bet() {
+	new_test_ext().execute_with(|| {
+		fund_house(HOUSE_BANKROLL);
+
+		let game_id = play_house(ALICE, ChallengePlay::Rock);
+
+		System::assert_last_event(Event::RpsModule(RpsEvent::HouseGameStarted {
+			game_id,
+			player: ALICE,
+			bet_amount: BET_AMOUNT,
+		}));
+		assert_eq!(reserved_balance(ALICE), BET_AMOUNT);
+		assert_eq!(RpsModule::house_exposure(), BET_AMOUNT);
+		assert_eq!(RpsModule::house_draws(2).into_inner(), vec![ALICE]);
+	});
+}
+
+#[test]
+fn play_house_fails_for_invalid_games() {
+	new_test_ext().execute_with(|| {
+		let play_hash = house_commitment(ALICE, ChallengePlay::Rock);
+		assert_noop!(
+			RpsModule::play_house(Origin::signed(ALICE), play_hash, BET_AMOUNT),
+			Error::<Test>::HouseExposureExceeded
+		);
+
+		fund_house(HOUSE_BANKROLL);
+		assert_noop!(
+			RpsModule::play_house(Origin::signed(ALICE), play_hash, MIN_BET_AMOUNT - 1),
+			Error::<Test>::InsufficientBetAmount
+		);
+		assert_noop!(
+			RpsModule::play_house(Origin::signed(DAVE), play_hash, MIN_BET_AMOUNT),
+			Error::<Test>::InsufficientFunds
+		);
+
+		play_house(ALICE, ChallengePlay::Rock);
+		assert_noop!(
+			RpsModule::play_house(Origin::signed(ALICE), play_hash, BET_AMOUNT),
+			Error::<Test>::HouseGameInProgress
+		);
+	});
+}
+
+#[test]
+fn house_exposure_is_limited_to_a_share_of_the_bankroll() {
+	new_test_ext().execute_with(|| {
+		fund_house(HOUSE_BANKROLL);
+		let max_exposure = HOUSE_BANKROLL * MAX_HOUSE_EXPOSURE_PERCENT as u64 / 100;
+
+		assert_ok!(RpsModule::play_house(
+			Origin::signed(ALICE),
+			house_commitment(ALICE, ChallengePlay::Rock),
+			max_exposure - MIN_BET_AMOUNT
+		));
+		assert_noop!(
+			RpsModule::play_house(
+				Origin::signed(BOB),
+				house_commitment(BOB, ChallengePlay::Rock),
+				MIN_BET_AMOUNT + 1
+			),
+			Error::<Test>::HouseExposureExceeded
+		);
+		assert_ok!(RpsModule::play_house(
+			Origin::signed(BOB),
+			house_commitment(BOB, ChallengePlay::Rock),
+			MIN_BET_AMOUNT
+		));
+		assert_eq!(RpsModule::house_exposure(), max_exposure);
+	});
+}
+
+#[test]
+fn house_games_started_in_a_block_are_bounded() {
+	new_test_ext().execute_with(|| {
+		fund_house(INITIAL_BALANCE);
+		play_house(ALICE, ChallengePlay::Rock);
+		play_house(BOB, ChallengePlay::Rock);
+
+		assert_noop!(
+			RpsModule::play_house(
+				Origin::signed(EVE),
+				house_commitment(EVE, ChallengePlay::Rock),
+				BET_AMOUNT
+			),
+			Error::<Test>::HouseBusy
+		);
+
+		run_to_block(2);
+		play_house(EVE, ChallengePlay::Rock);
+	});
+}
+
+#[test]
+fn house_hand_is_drawn_in_the_next_block() {
+	new_test_ext().execute_with(|| {
+		fund_house(HOUSE_BANKROLL);
+		let game_id = play_house(ALICE, ChallengePlay::Paper);
+
+		assert_noop!(
+			RpsModule::reveal_house(Origin::signed(ALICE), ChallengePlay::Paper, salt_of(ALICE)),
+			Error::<Test>::HouseHandNotDrawn
+		);
+
+		HouseSeed::set(0);
+		run_to_block(2);
+		System::assert_last_event(Event::RpsModule(RpsEvent::HouseHandDrawn {
+			game_id,
+			player: ALICE,
+			house_hand: ChallengePlay::Rock,
+		}));
+		assert!(RpsModule::house_draws(2).is_empty());
+
+		assert_noop!(
+			RpsModule::reveal_house(Origin::signed(ALICE), ChallengePlay::Rock, salt_of(ALICE)),
+			Error::<Test>::InvalidHandHash
+		);
+		assert_noop!(
+			RpsModule::reveal_house(Origin::signed(BOB), ChallengePlay::Paper, salt_of(BOB)),
+			Error::<Test>::HouseGameNotFound
+		);
+	});
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn reveal_house_settles_the_game() {
 	new_test_ext().execute_with(|| {
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(Origin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
+		fund_house(2 * HOUSE_BANKROLL);
+		let house = RpsModule::house_account_id();
+		HouseSeed::set(1);
+
+		let won = play_house(ALICE, ChallengePlay::Scissors);
+		let lost = play_house(BOB, ChallengePlay::Rock);
+		run_to_block(2);
+		let drawn = play_house(EVE, ChallengePlay::Paper);
+		run_to_block(3);
+
+		assert_ok!(RpsModule::reveal_house(
+			Origin::signed(ALICE),
+			ChallengePlay::Scissors,
+			salt_of(ALICE)
+		));
+		System::assert_last_event(Event::RpsModule(RpsEvent::HouseGameFinished {
+			game_id: won,
+			player: ALICE,
+			hand: Some(ChallengePlay::Scissors),
+			house_hand: Some(ChallengePlay::Paper),
+			result: PlayResult::Win,
+		}));
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE + BET_AMOUNT);
+
+		assert_ok!(RpsModule::reveal_house(Origin::signed(BOB), ChallengePlay::Rock, salt_of(BOB)));
+		System::assert_last_event(Event::RpsModule(RpsEvent::HouseGameFinished {
+			game_id: lost,
+			player: BOB,
+			hand: Some(ChallengePlay::Rock),
+			house_hand: Some(ChallengePlay::Paper),
+			result: PlayResult::Lose,
+		}));
+		assert_eq!(free_balance(BOB), INITIAL_BALANCE - BET_AMOUNT);
+		assert_eq!(reserved_balance(BOB), 0);
+
+		assert_ok!(RpsModule::reveal_house(
+			Origin::signed(EVE),
+			ChallengePlay::Paper,
+			salt_of(EVE)
+		));
+		System::assert_last_event(Event::RpsModule(RpsEvent::HouseGameFinished {
+			game_id: drawn,
+			player: EVE,
+			hand: Some(ChallengePlay::Paper),
+			house_hand: Some(ChallengePlay::Paper),
+			result: PlayResult::Draw,
+		}));
+		assert_eq!(free_balance(EVE), INITIAL_BALANCE);
+
+		assert_eq!(free_balance(house), 2 * HOUSE_BANKROLL);
+		assert_eq!(RpsModule::house_exposure(), 0);
+		assert!(RpsModule::house_game(ALICE).is_none());
+	});
+}
+
+#[test]
+fn house_commitments_do_not_depend_on_the_order_of_the_games() {
+	new_test_ext().execute_with(|| {
+		fund_house(2 * HOUSE_BANKROLL);
+		HouseSeed::set(1);
+
+		// Both commitments are computed before either game starts in the same block
+		let alice_hash = house_commitment(ALICE, ChallengePlay::Scissors);
+		let bob_hash = house_commitment(BOB, ChallengePlay::Rock);
+		assert_ok!(RpsModule::play_house(Origin::signed(BOB), bob_hash, BET_AMOUNT));
+		assert_ok!(RpsModule::play_house(Origin::signed(ALICE), alice_hash, BET_AMOUNT));
+		assert_eq!(RpsModule::house_nonce(ALICE), 1);
+		assert_eq!(RpsModule::house_nonce(BOB), 1);
+		run_to_block(2);
+
+		assert_ok!(RpsModule::reveal_house(
+			Origin::signed(ALICE),
+			ChallengePlay::Scissors,
+			salt_of(ALICE)
+		));
+		assert_ok!(RpsModule::reveal_house(Origin::signed(BOB), ChallengePlay::Rock, salt_of(BOB)));
+
+		// A commitment cannot be replayed in a later game
+		assert_ok!(RpsModule::play_house(Origin::signed(ALICE), alice_hash, BET_AMOUNT));
+		run_to_block(3);
+		assert_noop!(
+			RpsModule::reveal_house(Origin::signed(ALICE), ChallengePlay::Scissors, salt_of(ALICE)),
+			Error::<Test>::InvalidHandHash
+		);
+	});
+}
+
+#[test]
+fn house_hand_depends_on_the_block_including_the_commitment() {
+	// Same game and commitment, included in blocks with different hashes
+	let house_hands: Vec<_> = (0..8u8)
+		.map(|block_hash| {
+			let mut ext = new_test_ext();
+			ext.execute_with(|| {
+				HashSubjects::set(true);
+				fund_house(HOUSE_BANKROLL);
+				play_house(ALICE, ChallengePlay::Rock);
+
+				System::set_block_number(2);
+				System::set_parent_hash(sp_core::H256::repeat_byte(block_hash));
+				RpsModule::on_initialize(2);
+				RpsModule::house_game(ALICE)
+					.and_then(|game| game.house_hand)
+					.map(|(hand, _)| hand)
+			})
+		})
+		.collect();
+
+	assert!(house_hands.iter().all(Option::is_some));
+	assert!(house_hands.iter().any(|hand| *hand != house_hands[0]));
+}
+
+#[test]
+fn unrevealed_house_game_is_forfeited() {
+	new_test_ext().execute_with(|| {
+		fund_house(HOUSE_BANKROLL);
+		let game_id = play_house(ALICE, ChallengePlay::Rock);
+		run_to_block(2);
+
+		assert_noop!(
+			RpsModule::claim_house_timeout(Origin::signed(BOB), ALICE),
+			Error::<Test>::ChallengeNotTimedOut
+		);
+
+		System::set_block_number(2 + REVEAL_TIMEOUT);
+		assert_ok!(RpsModule::claim_house_timeout(Origin::signed(BOB), ALICE));
+		System::assert_last_event(Event::RpsModule(RpsEvent::HouseGameFinished {
+			game_id,
+			player: ALICE,
+			hand: None,
+			house_hand: Some(ChallengePlay::Rock),
+			result: PlayResult::Lose,
+		}));
+		assert_eq!(free_balance(ALICE), INITIAL_BALANCE - BET_AMOUNT);
+		assert_eq!(free_balance(RpsModule::house_account_id()), HOUSE_BANKROLL + BET_AMOUNT);
+		assert_noop!(
+			RpsModule::claim_house_timeout(Origin::signed(BOB), ALICE),
+			Error::<Test>::HouseGameNotFound
+		);
 	});
 }
 
+fn old_challenge_key(challenge_id: ChallengeId) -> Vec<u8> {
+	Blake2_128Concat::hash(&challenge_id.encode())
+}
+
+fn old_play_key(challenge_id: ChallengeId, player: u64) -> Vec<u8> {
+	[old_challenge_key(challenge_id), Blake2_128Concat::hash(&player.encode())].concat()
+}
+
 #[test]
-fn correct_error_for_none_value() {
+fn runtime_upgrade_refunds_and_drains_challenges_of_the_first_layout() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(TemplateModule::cause_error(Origin::signed(1)), Error::<Test>::NoneValue);
+		StorageVersion::new(0).put::<RpsModule>();
+		// Accepted challenge in which only Alice played, reserving her bet.
+		put_storage_value(
+			b"RpsModule",
+			b"ChallengeStore",
+			&old_challenge_key(0),
+			(1u8, ALICE, BOB, BET_AMOUNT),
+		);
+		put_storage_value(b"RpsModule", b"ChallengePlaysStore", &old_play_key(0, ALICE), [1u8; 8]);
+		assert_ok!(Balances::reserve(&ALICE, BET_AMOUNT));
+		// Open and finished challenges hold no reserved funds.
+		put_storage_value(
+			b"RpsModule",
+			b"ChallengeStore",
+			&old_challenge_key(1),
+			(0u8, CHARLIE, BET_AMOUNT),
+		);
+		put_storage_value(
+			b"RpsModule",
+			b"ChallengeStore",
+			&old_challenge_key(2),
+			(2u8, ALICE, BOB, BET_AMOUNT, Some(ALICE)),
+		);
+		put_storage_value(b"RpsModule", b"ChallengePlaysStore", &old_play_key(2, BOB), [2u8; 8]);
+
+		RpsModule::on_runtime_upgrade();
+
+		assert_eq!(RpsModule::on_chain_storage_version(), 1);
+		assert!(!have_storage_value(b"RpsModule", b"ChallengePlaysStore", &old_play_key(0, ALICE)));
+		assert!(!have_storage_value(b"RpsModule", b"ChallengePlaysStore", &old_play_key(2, BOB)));
+		for challenge_id in 0..3 {
+			assert!(!have_storage_value(
+				b"RpsModule",
+				b"ChallengeStore",
+				&old_challenge_key(challenge_id)
+			));
+		}
+		for player in [ALICE, BOB, CHARLIE] {
+			assert_eq!(reserved_balance(player), 0);
+			assert_eq!(Balances::free_balance(player), INITIAL_BALANCE);
+		}
+
+		// Running the upgrade again leaves the new layout untouched.
+		assert_ok!(RpsModule::create_challenge(Origin::signed(ALICE), None, BET_AMOUNT, 1, None));
+		RpsModule::on_runtime_upgrade();
+		assert_eq!(reserved_balance(ALICE), BET_AMOUNT + CHALLENGE_DEPOSIT);
 	});
 }
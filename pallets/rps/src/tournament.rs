@@ -0,0 +1,88 @@
+//! Single elimination tournaments, played as challenges between the players of each round.
+
+use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::{vec, vec::Vec};
+
+pub type TournamentId = u32;
+
+/// Index of a round within a tournament, starting at zero.
+pub type TournamentRound = u32;
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TournamentState<AccountId> {
+	/// Players can register until the registration deadline.
+	Registration,
+	/// The bracket has been seeded and the given round is being played.
+	Running(TournamentRound),
+	/// The last match has been played and the prize pool paid out.
+	Finished { champion: AccountId },
+	/// Registration closed with too few players, who got their entry fee back.
+	Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Tournament<AccountId, Balance, BlockNumber> {
+	pub(crate) organizer: AccountId,
+	/// Deposit reserved from the organizer until the tournament ends.
+	pub(crate) deposit: Balance,
+	pub(crate) entry_fee: Balance,
+	pub(crate) max_players: u32,
+	/// Last block in which players can register, the bracket being seeded in the next one.
+	pub(crate) registration_deadline: BlockNumber,
+	/// Number of rounds each match is played to, one of `ALLOWED_ROUNDS`.
+	pub(crate) rounds: u8,
+	/// Share of the prize pool of each place, see `elimination_round_of_place`.
+	pub(crate) prize_shares: Vec<Permill>,
+	/// Entry fees paid by the registered players.
+	pub(crate) prize_pool: Balance,
+	pub(crate) state: TournamentState<AccountId>,
+}
+
+/// Indices of the seeds in the slots of a bracket of `size` slots, a power of two. Pairing adjacent
+/// slots in every round matches the best seeds against the worst ones in the first round and keeps
+/// the two best seeds apart until the final: `[0, 7, 3, 4, 1, 6, 2, 5]` for 8 slots.
+pub fn bracket_order(size: usize) -> Vec<usize> {
+	let mut order = vec![0];
+	while order.len() < size {
+		let slots = order.len() * 2;
+		order = order.into_iter().flat_map(|seed| [seed, slots - 1 - seed]).collect();
+	}
+	order
+}
+
+/// Matches of the first round, in bracket order. The field is padded to the next power of two
+/// with byes, which go to the best seeds, so that no later round has a bye.
+///
+/// `seeds` must be sorted from the best seed to the worst one.
+pub fn first_round_pairings<AccountId: Clone>(
+	seeds: &[AccountId],
+) -> Vec<(AccountId, Option<AccountId>)> {
+	bracket_order(seeds.len().next_power_of_two())
+		.chunks(2)
+		.filter_map(|pair| {
+			let challenger = seeds.get(pair[0])?.clone();
+			Some((challenger, pair.get(1).and_then(|seed| seeds.get(*seed)).cloned()))
+		})
+		.collect()
+}
+
+/// Splits the roster of a later round into matches between adjacent players. Rosters always have
+/// an even number of players after a first round seeded with `first_round_pairings`.
+pub fn pairings<AccountId: Clone>(roster: &[AccountId]) -> Vec<(AccountId, Option<AccountId>)> {
+	roster.chunks(2).map(|pair| (pair[0].clone(), pair.get(1).cloned())).collect()
+}
+
+/// Round in which the players sharing the prize of the given place were eliminated, the first
+/// place going to the champion and every later one to the players knocked out a round earlier.
+/// Returns `None` for the first place and for places beyond the first round.
+pub fn elimination_round_of_place(
+	place: usize,
+	final_round: TournamentRound,
+) -> Option<TournamentRound> {
+	let rounds_before_final = (place as TournamentRound).checked_sub(1)?;
+	final_round.checked_sub(rounds_before_final)
+}
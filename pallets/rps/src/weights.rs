@@ -0,0 +1,573 @@
+//! Placeholder weights for pallet_rps
+//!
+//! These figures are estimates written by hand, not the output of the benchmarks: the storage
+//! accesses of each extrinsic follow its benchmark in `benchmarking.rs`, but the execution times
+//! are rough guesses. Replace this file with the figures measured on the reference hardware before
+//! relying on them to size blocks, by running:
+//!
+//! ```text
+//! ./target/release/node-rps benchmark \
+//!     --chain=dev \
+//!     --execution=wasm \
+//!     --wasm-execution=compiled \
+//!     --pallet=pallet_rps \
+//!     --extrinsic=* \
+//!     --steps=50 \
+//!     --repeat=20 \
+//!     --output=./pallets/rps/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_rps.
+pub trait WeightInfo {
+	fn create_challenge() -> Weight;
+	fn create_challenge_for() -> Weight;
+	fn cancel_challenge() -> Weight;
+	fn enter_challenge() -> Weight;
+	fn quick_join() -> Weight;
+	fn join_queue() -> Weight;
+	fn leave_queue() -> Weight;
+	fn set_asset_min_bet() -> Weight;
+	fn create_tournament() -> Weight;
+	fn join_tournament() -> Weight;
+	fn seed_tournament_round(p: u32, ) -> Weight;
+	fn finish_tournament(p: u32, ) -> Weight;
+	fn fund_season_pool() -> Weight;
+	fn end_season(w: u32, ) -> Weight;
+	fn play_house() -> Weight;
+	fn reveal_house() -> Weight;
+	fn claim_house_timeout() -> Weight;
+	fn draw_house_hands(h: u32, ) -> Weight;
+	fn play_challenge() -> Weight;
+	fn reveal_play() -> Weight;
+	fn reveal_challenge_results() -> Weight;
+	fn claim_timeout() -> Weight;
+	fn prune_challenge() -> Weight;
+}
+
+/// Estimated weights for pallet_rps, to be replaced by benchmarked ones.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: RpsModule NextBetId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule ChallengeStore (r:0 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule Lobby (r:1 w:1)
+	fn create_challenge() -> Weight {
+		(37_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule NextBetId (r:1 w:1)
+	// Storage: RpsModule ChallengeStore (r:0 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	fn create_challenge_for() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule Lobby (r:1 w:1)
+	fn cancel_challenge() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule Lobby (r:1 w:1)
+	// Storage: RpsModule Ratings (r:1 w:0)
+	fn enter_challenge() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule Lobby (r:1 w:1)
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule Ratings (r:1 w:0)
+	fn quick_join() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule MatchmakingQueue (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule NextBetId (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:2)
+	// Storage: RpsModule ChallengeStore (r:0 w:1)
+	fn join_queue() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RpsModule MatchmakingQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	fn leave_queue() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: RpsModule AssetMinBet (r:0 w:1)
+	fn set_asset_min_bet() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RpsModule ActiveTournamentCount (r:1 w:1)
+	// Storage: RpsModule NextTournamentId (r:1 w:1)
+	// Storage: RpsModule TournamentSchedule (r:1 w:1)
+	// Storage: RpsModule Tournaments (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn create_tournament() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule Tournaments (r:1 w:1)
+	// Storage: RpsModule TournamentPlayers (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn join_tournament() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RpsModule Tournaments (r:1 w:1)
+	// Storage: RpsModule TournamentPlayers (r:1 w:0)
+	// Storage: RpsModule Ratings (r:1 w:0)
+	// Storage: RpsModule NextBetId (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: RpsModule ChallengeStore (r:0 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule TournamentMatches (r:0 w:1)
+	// Storage: RpsModule TournamentBracket (r:0 w:1)
+	fn seed_tournament_round(p: u32, ) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((16_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengeRevealsStore (r:2 w:1)
+	// Storage: RpsModule ChallengePlaysStore (r:0 w:2)
+	// Storage: RpsModule ActiveChallengeCount (r:2 w:2)
+	// Storage: RpsModule Ratings (r:2 w:2)
+	// Storage: RpsModule PlayerStats (r:2 w:2)
+	// Storage: RpsModule CurrentSeason (r:1 w:0)
+	// Storage: RpsModule PlayerSeasonPoints (r:2 w:2)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule TournamentMatches (r:1 w:1)
+	// Storage: RpsModule Tournaments (r:1 w:1)
+	// Storage: RpsModule TournamentBracket (r:1 w:1)
+	// Storage: RpsModule TournamentEliminations (r:1 w:1)
+	// Storage: RpsModule TournamentPlayers (r:1 w:1)
+	// Storage: RpsModule ActiveTournamentCount (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn finish_tournament(p: u32, ) -> Weight {
+		(96_000_000 as Weight)
+			.saturating_add((27_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	fn fund_season_pool() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: RpsModule CurrentSeason (r:1 w:1)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule SeasonResults (r:0 w:1)
+	fn end_season(w: u32, ) -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add((24_000_000 as Weight).saturating_mul(w as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(w as Weight)))
+	}
+	// Storage: RpsModule HouseGames (r:1 w:1)
+	// Storage: RpsModule HouseExposure (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: RpsModule HouseDraws (r:1 w:1)
+	// Storage: RpsModule NextHouseGameId (r:1 w:1)
+	// Storage: RpsModule HouseNonces (r:1 w:1)
+	fn play_house() -> Weight {
+		(43_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: RpsModule HouseGames (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule HouseExposure (r:1 w:1)
+	fn reveal_house() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RpsModule HouseGames (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule HouseExposure (r:1 w:1)
+	fn claim_house_timeout() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RpsModule HouseDraws (r:1 w:1)
+	// Storage: RpsModule HouseGames (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	fn draw_house_hands(h: u32, ) -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add((14_000_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(h as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengePlaysStore (r:3 w:1)
+	fn play_challenge() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengeRevealsStore (r:2 w:2)
+	// Storage: RpsModule ChallengePlaysStore (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule ActiveChallengeCount (r:2 w:2)
+	// Storage: RpsModule Ratings (r:2 w:2)
+	// Storage: RpsModule PlayerStats (r:2 w:2)
+	// Storage: RpsModule CurrentSeason (r:1 w:0)
+	// Storage: RpsModule PlayerSeasonPoints (r:2 w:2)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	fn reveal_play() -> Weight {
+		(86_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengePlaysStore (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule ActiveChallengeCount (r:2 w:2)
+	// Storage: RpsModule Ratings (r:2 w:2)
+	// Storage: RpsModule PlayerStats (r:2 w:2)
+	// Storage: RpsModule CurrentSeason (r:1 w:0)
+	// Storage: RpsModule PlayerSeasonPoints (r:2 w:2)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	fn reveal_challenge_results() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(15 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengeRevealsStore (r:2 w:1)
+	// Storage: RpsModule ChallengePlaysStore (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule ActiveChallengeCount (r:2 w:2)
+	// Storage: RpsModule Ratings (r:2 w:2)
+	// Storage: RpsModule PlayerStats (r:2 w:2)
+	// Storage: RpsModule CurrentSeason (r:1 w:0)
+	// Storage: RpsModule PlayerSeasonPoints (r:2 w:2)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	fn claim_timeout() -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule PlayerChallenges (r:0 w:2)
+	fn prune_challenge() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: RpsModule NextBetId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule ChallengeStore (r:0 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule Lobby (r:1 w:1)
+	fn create_challenge() -> Weight {
+		(37_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule NextBetId (r:1 w:1)
+	// Storage: RpsModule ChallengeStore (r:0 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	fn create_challenge_for() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule Lobby (r:1 w:1)
+	fn cancel_challenge() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule Lobby (r:1 w:1)
+	// Storage: RpsModule Ratings (r:1 w:0)
+	fn enter_challenge() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule Lobby (r:1 w:1)
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule Ratings (r:1 w:0)
+	fn quick_join() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule MatchmakingQueue (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule NextBetId (r:1 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:2)
+	// Storage: RpsModule ChallengeStore (r:0 w:1)
+	fn join_queue() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RpsModule MatchmakingQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	fn leave_queue() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: RpsModule AssetMinBet (r:0 w:1)
+	fn set_asset_min_bet() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RpsModule ActiveTournamentCount (r:1 w:1)
+	// Storage: RpsModule NextTournamentId (r:1 w:1)
+	// Storage: RpsModule TournamentSchedule (r:1 w:1)
+	// Storage: RpsModule Tournaments (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn create_tournament() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RpsModule Tournaments (r:1 w:1)
+	// Storage: RpsModule TournamentPlayers (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn join_tournament() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RpsModule Tournaments (r:1 w:1)
+	// Storage: RpsModule TournamentPlayers (r:1 w:0)
+	// Storage: RpsModule Ratings (r:1 w:0)
+	// Storage: RpsModule NextBetId (r:1 w:1)
+	// Storage: RpsModule ActiveChallengeCount (r:1 w:1)
+	// Storage: RpsModule ChallengeStore (r:0 w:1)
+	// Storage: RpsModule PlayerChallenges (r:0 w:1)
+	// Storage: RpsModule TournamentMatches (r:0 w:1)
+	// Storage: RpsModule TournamentBracket (r:0 w:1)
+	fn seed_tournament_round(p: u32, ) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((16_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengeRevealsStore (r:2 w:1)
+	// Storage: RpsModule ChallengePlaysStore (r:0 w:2)
+	// Storage: RpsModule ActiveChallengeCount (r:2 w:2)
+	// Storage: RpsModule Ratings (r:2 w:2)
+	// Storage: RpsModule PlayerStats (r:2 w:2)
+	// Storage: RpsModule CurrentSeason (r:1 w:0)
+	// Storage: RpsModule PlayerSeasonPoints (r:2 w:2)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule TournamentMatches (r:1 w:1)
+	// Storage: RpsModule Tournaments (r:1 w:1)
+	// Storage: RpsModule TournamentBracket (r:1 w:1)
+	// Storage: RpsModule TournamentEliminations (r:1 w:1)
+	// Storage: RpsModule TournamentPlayers (r:1 w:1)
+	// Storage: RpsModule ActiveTournamentCount (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn finish_tournament(p: u32, ) -> Weight {
+		(96_000_000 as Weight)
+			.saturating_add((27_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	fn fund_season_pool() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: RpsModule CurrentSeason (r:1 w:1)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RpsModule SeasonResults (r:0 w:1)
+	fn end_season(w: u32, ) -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add((24_000_000 as Weight).saturating_mul(w as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(w as Weight)))
+	}
+	// Storage: RpsModule HouseGames (r:1 w:1)
+	// Storage: RpsModule HouseExposure (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: RpsModule HouseDraws (r:1 w:1)
+	// Storage: RpsModule NextHouseGameId (r:1 w:1)
+	// Storage: RpsModule HouseNonces (r:1 w:1)
+	fn play_house() -> Weight {
+		(43_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: RpsModule HouseGames (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule HouseExposure (r:1 w:1)
+	fn reveal_house() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RpsModule HouseGames (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule HouseExposure (r:1 w:1)
+	fn claim_house_timeout() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RpsModule HouseDraws (r:1 w:1)
+	// Storage: RpsModule HouseGames (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	fn draw_house_hands(h: u32, ) -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add((14_000_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(h as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengePlaysStore (r:3 w:1)
+	fn play_challenge() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengeRevealsStore (r:2 w:2)
+	// Storage: RpsModule ChallengePlaysStore (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule ActiveChallengeCount (r:2 w:2)
+	// Storage: RpsModule Ratings (r:2 w:2)
+	// Storage: RpsModule PlayerStats (r:2 w:2)
+	// Storage: RpsModule CurrentSeason (r:1 w:0)
+	// Storage: RpsModule PlayerSeasonPoints (r:2 w:2)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	fn reveal_play() -> Weight {
+		(86_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengePlaysStore (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule ActiveChallengeCount (r:2 w:2)
+	// Storage: RpsModule Ratings (r:2 w:2)
+	// Storage: RpsModule PlayerStats (r:2 w:2)
+	// Storage: RpsModule CurrentSeason (r:1 w:0)
+	// Storage: RpsModule PlayerSeasonPoints (r:2 w:2)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	fn reveal_challenge_results() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: RpsModule ChallengeRevealsStore (r:2 w:1)
+	// Storage: RpsModule ChallengePlaysStore (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule ActiveChallengeCount (r:2 w:2)
+	// Storage: RpsModule Ratings (r:2 w:2)
+	// Storage: RpsModule PlayerStats (r:2 w:2)
+	// Storage: RpsModule CurrentSeason (r:1 w:0)
+	// Storage: RpsModule PlayerSeasonPoints (r:2 w:2)
+	// Storage: RpsModule Leaderboard (r:1 w:1)
+	// Storage: RpsModule SeasonPool (r:1 w:1)
+	fn claim_timeout() -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(16 as Weight))
+	}
+	// Storage: RpsModule ChallengeStore (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RpsModule PlayerChallenges (r:0 w:2)
+	fn prune_challenge() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+}
@@ -6,6 +6,8 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use frame_support::PalletId;
+use frame_system::EnsureRoot;
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
@@ -42,6 +44,7 @@ pub use sp_runtime::{Perbill, Permill};
 
 /// Import the template pallet.
 pub use pallet_rps;
+use pallet_rps_runtime_api::Arena;
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -62,6 +65,12 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// Identifier of an asset.
+pub type AssetId = u32;
+
+/// Hand played in rock-paper-scissors challenges.
+pub type Hand = pallet_rps::HandOf<Runtime>;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -98,10 +107,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -266,8 +275,90 @@ impl pallet_sudo::Config for Runtime {
 	type Call = Call;
 }
 
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = 100_000;
+	pub const SpendPeriod: BlockNumber = DAYS;
+	pub const Burn: Permill = Permill::zero();
+	pub const MaxApprovals: u32 = 100;
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = EnsureRoot<AccountId>;
+	type RejectOrigin = EnsureRoot<AccountId>;
+	type Event = Event;
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type ProposalBondMaximum = ();
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxApprovals;
+}
+
+parameter_types! {
+	pub const AssetDeposit: Balance = 100_000;
+	pub const ApprovalDeposit: Balance = 1_000;
+	pub const AssetsStringLimit: u32 = 50;
+	pub const MetadataDepositBase: Balance = 10_000;
+	pub const MetadataDepositPerByte: Balance = 100;
+}
+
+impl pallet_assets::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = AssetId;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const MinBetAmount: u64 = 100;
+	pub const PlayTimeout: BlockNumber = HOURS;
+	pub const RevealTimeout: BlockNumber = HOURS;
+	pub const ChallengeDeposit: Balance = 1_000;
+	pub const FinishedRetention: BlockNumber = 7 * DAYS;
+	pub const MaxActiveChallengesPerAccount: u32 = 16;
+	pub const MaxLobbySize: u32 = 256;
+	pub const RakePercent: Permill = Permill::from_percent(2);
+	pub const RpsPalletId: PalletId = PalletId(*b"py/rpsgm");
+	pub const RpsHighStakesPalletId: PalletId = PalletId(*b"py/rpshs");
+	pub TreasuryAccount: AccountId = Treasury::account_id();
+	pub const StartingRating: u32 = 1_500;
+	pub const KFactor: u32 = 32;
+	pub const MaxTournamentPlayers: u32 = 64;
+	pub const MaxPrizeTiers: u32 = 8;
+	pub const MaxActiveTournaments: u32 = 16;
+	pub const TournamentDeposit: Balance = 10_000;
+	pub const MaxRegistrationPeriod: BlockNumber = 7 * DAYS;
+	pub const SeasonLength: BlockNumber = 28 * DAYS;
+	pub const LeaderboardSize: u32 = 100;
+	pub SeasonPrizeShares: Vec<Permill> = vec![
+		Permill::from_percent(40),
+		Permill::from_percent(25),
+		Permill::from_percent(15),
+		Permill::from_percent(10),
+		Permill::from_percent(10),
+	];
+	pub const SeasonRakeShare: Permill = Permill::from_percent(50);
+	pub const MaxHouseExposure: Permill = Permill::from_percent(10);
+	pub const MaxHouseGamesPerBlock: u32 = 64;
+	pub const HighStakesMinBetAmount: Balance = 1_000_000;
+	pub const HighStakesChallengeDeposit: Balance = 10_000;
 }
 
 /// Configure the pallet-rps in pallets/rps.
@@ -275,6 +366,69 @@ impl pallet_rps::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type MinBetAmount = MinBetAmount;
+	type AssetId = AssetId;
+	type Assets = Assets;
+	type PalletId = RpsPalletId;
+	type AssetFeeCollector = TreasuryAccount;
+	type PlayTimeout = PlayTimeout;
+	type RevealTimeout = RevealTimeout;
+	type ChallengeDeposit = ChallengeDeposit;
+	type FinishedRetention = FinishedRetention;
+	type MaxActiveChallengesPerAccount = MaxActiveChallengesPerAccount;
+	type MaxLobbySize = MaxLobbySize;
+	type GameRules = pallet_rps::ClassicRules;
+	type RakePercent = RakePercent;
+	type FeeDestination = Treasury;
+	type StartingRating = StartingRating;
+	type KFactor = KFactor;
+	type MaxTournamentPlayers = MaxTournamentPlayers;
+	type MaxPrizeTiers = MaxPrizeTiers;
+	type MaxActiveTournaments = MaxActiveTournaments;
+	type TournamentDeposit = TournamentDeposit;
+	type MaxRegistrationPeriod = MaxRegistrationPeriod;
+	type SeasonLength = SeasonLength;
+	type LeaderboardSize = LeaderboardSize;
+	type SeasonPrizeShares = SeasonPrizeShares;
+	type SeasonRakeShare = SeasonRakeShare;
+	type Randomness = RandomnessCollectiveFlip;
+	type MaxHouseExposure = MaxHouseExposure;
+	type MaxHouseGamesPerBlock = MaxHouseGamesPerBlock;
+	type WeightInfo = pallet_rps::weights::SubstrateWeight<Runtime>;
+}
+
+/// Configure a second, high stakes arena of pallet-rps.
+impl pallet_rps::Config<pallet_rps::Instance1> for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type MinBetAmount = HighStakesMinBetAmount;
+	type AssetId = AssetId;
+	type Assets = Assets;
+	type PalletId = RpsHighStakesPalletId;
+	type AssetFeeCollector = TreasuryAccount;
+	type PlayTimeout = PlayTimeout;
+	type RevealTimeout = RevealTimeout;
+	type ChallengeDeposit = HighStakesChallengeDeposit;
+	type FinishedRetention = FinishedRetention;
+	type MaxActiveChallengesPerAccount = MaxActiveChallengesPerAccount;
+	type MaxLobbySize = MaxLobbySize;
+	type GameRules = pallet_rps::ClassicRules;
+	type RakePercent = RakePercent;
+	type FeeDestination = Treasury;
+	type StartingRating = StartingRating;
+	type KFactor = KFactor;
+	type MaxTournamentPlayers = MaxTournamentPlayers;
+	type MaxPrizeTiers = MaxPrizeTiers;
+	type MaxActiveTournaments = MaxActiveTournaments;
+	type TournamentDeposit = TournamentDeposit;
+	type MaxRegistrationPeriod = MaxRegistrationPeriod;
+	type SeasonLength = SeasonLength;
+	type LeaderboardSize = LeaderboardSize;
+	type SeasonPrizeShares = SeasonPrizeShares;
+	type SeasonRakeShare = SeasonRakeShare;
+	type Randomness = RandomnessCollectiveFlip;
+	type MaxHouseExposure = MaxHouseExposure;
+	type MaxHouseGamesPerBlock = MaxHouseGamesPerBlock;
+	type WeightInfo = pallet_rps::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -292,8 +446,11 @@ construct_runtime!(
 		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
+		Treasury: pallet_treasury,
+		Assets: pallet_assets,
 		// Include the custom logic from the pallet-template in the runtime.
 		RpsModule: pallet_rps,
+		RpsHighStakes: pallet_rps::<Instance1>,
 	}
 );
 
@@ -336,6 +493,8 @@ mod benches {
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
+		[pallet_treasury, Treasury]
+		[pallet_assets, Assets]
 		[pallet_rps, RpsModule]
 	);
 }
@@ -471,6 +630,77 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_rps_runtime_api::RpsApi<Block, AccountId, Balance, BlockNumber, Hand, AssetId> for Runtime {
+		fn challenge(
+			arena: Arena,
+			challenge_id: pallet_rps::ChallengeId,
+		) -> Option<pallet_rps::ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>> {
+			match arena {
+				Arena::Main => RpsModule::challenge(challenge_id),
+				Arena::Instance1 => RpsHighStakes::challenge(challenge_id),
+			}
+		}
+
+		fn open_challenges(
+			arena: Arena,
+			start: pallet_rps::ChallengeId,
+			count: u32,
+		) -> (
+			Vec<(pallet_rps::ChallengeId, pallet_rps::ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>)>,
+			Option<pallet_rps::ChallengeId>,
+		) {
+			match arena {
+				Arena::Main => RpsModule::open_challenges(start, count),
+				Arena::Instance1 => RpsHighStakes::open_challenges(start, count),
+			}
+		}
+
+		fn challenges_of(
+			arena: Arena,
+			account: AccountId,
+		) -> Vec<(pallet_rps::ChallengeId, pallet_rps::ChallengeState<AccountId, Balance, BlockNumber, Hand, AssetId>)> {
+			match arena {
+				Arena::Main => RpsModule::challenges_of(&account),
+				Arena::Instance1 => RpsHighStakes::challenges_of(&account),
+			}
+		}
+
+		fn commitment_status(
+			arena: Arena,
+			challenge_id: pallet_rps::ChallengeId,
+			account: AccountId,
+		) -> Option<pallet_rps::CommitmentStatus<Hand>> {
+			match arena {
+				Arena::Main => RpsModule::commitment_status(challenge_id, &account),
+				Arena::Instance1 => RpsHighStakes::commitment_status(challenge_id, &account),
+			}
+		}
+
+		fn player_stats(arena: Arena, account: AccountId) -> pallet_rps::PlayerStatistics<Balance, Hand> {
+			match arena {
+				Arena::Main => RpsModule::stats_of(&account),
+				Arena::Instance1 => RpsHighStakes::stats_of(&account),
+			}
+		}
+
+		fn leaderboard(arena: Arena) -> Vec<(AccountId, pallet_rps::SeasonPoints)> {
+			match arena {
+				Arena::Main => RpsModule::leaderboard().into_inner(),
+				Arena::Instance1 => RpsHighStakes::leaderboard().into_inner(),
+			}
+		}
+
+		fn season_result(
+			arena: Arena,
+			season: pallet_rps::SeasonId,
+		) -> Option<pallet_rps::SeasonResult<AccountId, Balance, BlockNumber>> {
+			match arena {
+				Arena::Main => RpsModule::season_result(season),
+				Arena::Instance1 => RpsHighStakes::season_result(season),
+			}
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (